@@ -0,0 +1,142 @@
+use clippy_utils::bound_removal_spans;
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{GenericBound, Generics, PredicateOrigin, TraitBoundModifier, WherePredicate};
+use rustc_hir_analysis::hir_ty_to_ty;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Clause, ImplPolarity, PredicateKind};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for trait bounds on generic parameters where the trait has an unconditional blanket
+    /// implementation in the same crate, such as `impl<T> Trait for T {}`.
+    ///
+    /// ### Why is this bad?
+    /// Every type implements the trait, so the bound is always satisfied and only adds noise.
+    ///
+    /// ### Example
+    /// ```rust
+    /// trait Marker {}
+    /// impl<T> Marker for T {}
+    ///
+    /// fn f<T: Marker>(x: T) {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// trait Marker {}
+    /// impl<T> Marker for T {}
+    ///
+    /// fn f<T>(x: T) {}
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub BOUND_IMPLIED_BY_BLANKET_IMPL,
+    complexity,
+    "trait bounds on generic parameters that are always satisfied because of a blanket impl"
+}
+declare_lint_pass!(BoundImpliedByBlanketImpl => [BOUND_IMPLIED_BY_BLANKET_IMPL]);
+
+/// Finds an `impl<T> Trait for T` in the current crate with no bounds on `T`, apart from the
+/// implicit `T: Sized` if the bounded type is known to be sized.
+fn unconditional_blanket_impl(cx: &LateContext<'_>, trait_id: DefId, bounded_is_sized: bool) -> Option<DefId> {
+    // Only plain traits, `Self` being their only generic parameter
+    if cx.tcx.generics_of(trait_id).count() != 1 {
+        return None;
+    }
+    let sized_trait = cx.tcx.lang_items().sized_trait();
+
+    cx.tcx
+        .trait_impls_of(trait_id)
+        .blanket_impls()
+        .iter()
+        .copied()
+        .find(|&impl_id| {
+            impl_id.is_local()
+                && cx.tcx.impl_polarity(impl_id) == ImplPolarity::Positive
+                && cx.tcx.generics_of(impl_id).count() == 1
+                && cx.tcx.impl_trait_ref(impl_id).map_or(false, |trait_ref| {
+                    matches!(trait_ref.subst_identity().self_ty().kind(), ty::Param(_))
+                })
+                && cx.tcx.predicates_of(impl_id).predicates.iter().all(|(predicate, _)| {
+                    matches!(
+                        predicate.kind().skip_binder(),
+                        PredicateKind::Clause(Clause::Trait(trait_predicate))
+                            if bounded_is_sized && Some(trait_predicate.def_id()) == sized_trait
+                    )
+                })
+        })
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoundImpliedByBlanketImpl {
+    fn check_generics(&mut self, cx: &LateContext<'tcx>, gen: &'tcx Generics<'tcx>) {
+        if gen.span.from_expansion() {
+            return;
+        }
+
+        for (pred_pos, predicate) in gen.predicates.iter().enumerate() {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            // Bounds of `impl Trait` can't be removed without replacing the whole type
+            if bound_predicate.origin == PredicateOrigin::ImplTrait
+                || bound_predicate.span.from_expansion()
+                || !bound_predicate.bound_generic_params.is_empty()
+            {
+                continue;
+            }
+            if bound_predicate.bounded_ty.as_generic_param().is_none() {
+                continue;
+            }
+            // The parameter can be bound by `?Sized` by a parent, like the impl of a method
+            let owner = bound_predicate.bounded_ty.hir_id.owner.to_def_id();
+            let is_sized = hir_ty_to_ty(cx.tcx, bound_predicate.bounded_ty).is_sized(cx.tcx, cx.tcx.param_env(owner));
+
+            let redundant = bound_predicate
+                .bounds
+                .iter()
+                .enumerate()
+                .filter_map(|(bound_pos, bound)| {
+                    let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound else {
+                        return None;
+                    };
+                    let Res::Def(DefKind::Trait, trait_id) = poly_trait_ref.trait_ref.path.res else {
+                        return None;
+                    };
+                    if !poly_trait_ref.bound_generic_params.is_empty() {
+                        return None;
+                    }
+                    let impl_id = unconditional_blanket_impl(cx, trait_id, is_sized)?;
+                    Some((bound_pos, impl_id))
+                })
+                .collect::<Vec<_>>();
+
+            let emit = |span: Span, impl_id: DefId| {
+                span_lint_and_then(
+                    cx,
+                    BOUND_IMPLIED_BY_BLANKET_IMPL,
+                    span,
+                    "this bound is always satisfied",
+                    |diag| {
+                        diag.span_suggestion(span, "remove the bound", "", Applicability::MachineApplicable);
+                        diag.span_note(
+                            cx.tcx.def_span(impl_id),
+                            "the trait is implemented for all types by this blanket impl",
+                        );
+                    },
+                );
+            };
+
+            // If the whole predicate is removed, the note points at the blanket impl of its first bound
+            let bound_positions = redundant.iter().map(|&(bound_pos, _)| bound_pos).collect::<Vec<_>>();
+            for (span, &(_, impl_id)) in bound_removal_spans(gen, pred_pos, &bound_positions)
+                .into_iter()
+                .zip(&redundant)
+            {
+                emit(span, impl_id);
+            }
+        }
+    }
+}
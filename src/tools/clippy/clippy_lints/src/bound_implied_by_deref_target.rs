@@ -0,0 +1,259 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::{implements_trait_with_env, param_env_without, param_index, signature_param_uses};
+use clippy_utils::{bound_removal_spans, trait_ref_of_method};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::{walk_expr, Visitor};
+use rustc_hir::{
+    BodyId, Expr, ExprKind, GenericBound, Generics, ImplItem, ImplItemKind, Item, ItemKind, Mutability,
+    PredicateOrigin, TraitBoundModifier, WherePredicate,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::ty::{self, Clause, PredicateKind, Ty, TypeckResults};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for trait bounds on a type parameter `T: Deref<Target = U>` of a function, when the
+    /// target `U` is bound by the same trait and the bound on `T` is only used to call methods
+    /// taking `&self`, which auto-deref would resolve to the implementation of `U` without it.
+    ///
+    /// The lint only fires in a narrow case, all of these must hold:
+    /// * the target `U` is a type parameter, so that it has no inherent methods,
+    /// * the bounds of `T` are only `Deref` and the trait, which has no generic parameters,
+    /// * `U` implements the trait without the bound on `T`,
+    /// * `T` is only mentioned by the signature as the type of an argument or a reference to it,
+    /// * the body only uses `T` for method calls of the trait taking `&self`.
+    ///
+    /// ### Why is this bad?
+    /// The bound restricts the callers of the function to deref types implementing the trait
+    /// themselves, while the target implementing it is enough.
+    ///
+    /// ### Known problems
+    /// Removing the bound changes which implementation is called: the function will use the
+    /// implementation of `U` instead of the one of `T`, which can behave differently.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::ops::Deref;
+    /// trait Describe {
+    ///     fn describe(&self) -> String;
+    /// }
+    ///
+    /// fn describe<T: Deref<Target = U> + Describe, U: Describe>(x: T) -> String {
+    ///     x.describe()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::ops::Deref;
+    /// trait Describe {
+    ///     fn describe(&self) -> String;
+    /// }
+    ///
+    /// fn describe<T: Deref<Target = U>, U: Describe>(x: T) -> String {
+    ///     x.describe()
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub BOUND_IMPLIED_BY_DEREF_TARGET,
+    nursery,
+    "trait bounds on `Deref` type parameters that auto-deref to a target implementing the trait"
+}
+declare_lint_pass!(BoundImpliedByDerefTarget => [BOUND_IMPLIED_BY_DEREF_TARGET]);
+
+/// A visitor struct that collects how the type parameters are used by the expressions of a body.
+struct ParamUses<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck: &'tcx TypeckResults<'tcx>,
+    /// The type parameters and traits of the calls to trait methods taking `&self`, on a receiver
+    /// whose type is the type parameter.
+    method_calls: FxHashSet<(u32, DefId)>,
+    /// The type parameters used in any other way which could require their bounds.
+    used: FxHashSet<u32>,
+}
+
+impl<'a, 'tcx> ParamUses<'a, 'tcx> {
+    /// Returns the type parameter and the trait of the method call, if it could also resolve
+    /// through auto-deref.
+    fn ref_self_method_call(&self, expr: &Expr<'_>) -> Option<(u32, DefId)> {
+        if !matches!(expr.kind, ExprKind::MethodCall(..)) {
+            return None;
+        }
+        let method_id = self.typeck.type_dependent_def_id(expr.hir_id)?;
+        let trait_id = self.cx.tcx.trait_of_item(method_id)?;
+        let param = param_index(self.typeck.node_substs(expr.hir_id).get(0).copied()?)?;
+        let fn_sig = self.cx.tcx.fn_sig(method_id).subst_identity().skip_binder();
+        match fn_sig.inputs().first()?.kind() {
+            ty::Ref(_, self_ty, Mutability::Not) if self_ty.is_param(0) => Some((param, trait_id)),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ParamUses<'a, 'tcx> {
+    // Closures share the typeck results of the function
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        let substs = self.typeck.node_substs_opt(expr.hir_id);
+        if let Some(call) = self.ref_self_method_call(expr) {
+            self.method_calls.insert(call);
+            if let Some(substs) = substs {
+                self.used
+                    .extend(substs.iter().skip(1).flat_map(|arg| arg.walk()).filter_map(param_index));
+            }
+        } else if let Some(substs) = substs {
+            self.used
+                .extend(substs.iter().flat_map(|arg| arg.walk()).filter_map(param_index));
+        }
+        walk_expr(self, expr);
+    }
+}
+
+fn check_fn<'tcx>(cx: &LateContext<'tcx>, def_id: LocalDefId, generics: &'tcx Generics<'tcx>, body_id: BodyId) {
+    let lang_items = cx.tcx.lang_items();
+    let (Some(deref_id), Some(deref_target_id), Some(sized_id)) =
+        (lang_items.deref_trait(), lang_items.deref_target(), lang_items.sized_trait())
+    else {
+        return;
+    };
+    let Some(signature_uses) = signature_param_uses(cx.tcx, def_id.to_def_id()) else {
+        return;
+    };
+
+    // The `Deref` targets of the type parameters, and their other trait bounds
+    let mut targets: FxHashMap<u32, Ty<'tcx>> = FxHashMap::default();
+    let mut bounds: FxHashMap<u32, Vec<DefId>> = FxHashMap::default();
+    for (index, trait_predicate) in signature_uses.trait_predicates {
+        let trait_id = trait_predicate.def_id();
+        if trait_id != deref_id && trait_id != sized_id {
+            bounds.entry(index).or_default().push(trait_id);
+        }
+    }
+    for predicate in signature_uses.other_predicates {
+        // Other predicates than the `Deref` targets can need the bounds of the type parameters
+        let PredicateKind::Clause(Clause::Projection(projection_predicate)) = predicate else {
+            return;
+        };
+        let (Some(index), Some(target)) = (
+            param_index(projection_predicate.projection_ty.self_ty().into()),
+            projection_predicate.term.ty(),
+        ) else {
+            return;
+        };
+        if projection_predicate.projection_ty.def_id != deref_target_id || !matches!(target.kind(), ty::Param(_)) {
+            return;
+        }
+        targets.insert(index, target);
+    }
+    if targets.is_empty() {
+        return;
+    }
+
+    let mut uses = ParamUses {
+        cx,
+        typeck: cx.tcx.typeck_body(body_id),
+        method_calls: FxHashSet::default(),
+        used: signature_uses.used,
+    };
+    uses.visit_body(cx.tcx.hir().body(body_id));
+
+    let generics_of = cx.tcx.generics_of(def_id);
+    let param_env = cx.tcx.param_env(def_id);
+    let implied = |index: u32, trait_id: DefId| {
+        let Some(&target) = targets.get(&index) else {
+            return false;
+        };
+        let is_only_bound = bounds.get(&index).map_or(false, |bounds| bounds == &[trait_id]);
+        if !is_only_bound
+            || cx.tcx.generics_of(trait_id).count() != 1
+            || uses.used.contains(&index)
+            || !uses.method_calls.contains(&(index, trait_id))
+        {
+            return false;
+        }
+        // Whether the target implements the trait without the bound on the type parameter
+        let param_env = param_env_without(cx.tcx, param_env, |trait_predicate| {
+            trait_predicate.def_id() == trait_id && trait_predicate.self_ty().is_param(index)
+        });
+        implements_trait_with_env(cx.tcx, param_env, target, trait_id, [])
+    };
+
+    let emit = |span: Span, target: Ty<'_>| {
+        span_lint_and_help(
+            cx,
+            BOUND_IMPLIED_BY_DEREF_TARGET,
+            span,
+            "this bound is only used by method calls which auto-deref would resolve without it",
+            None,
+            &format!("consider removing the bound, the calls would then use the implementation of `{target}`"),
+        );
+    };
+    for (pred_pos, predicate) in generics.predicates.iter().enumerate() {
+        let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+            continue;
+        };
+        if bound_predicate.origin == PredicateOrigin::ImplTrait
+            || bound_predicate.span.from_expansion()
+            || !bound_predicate.bound_generic_params.is_empty()
+        {
+            continue;
+        }
+        let Some((param_id, _)) = bound_predicate.bounded_ty.as_generic_param() else {
+            continue;
+        };
+        let Some(index) = generics_of.param_def_id_to_index(cx.tcx, param_id) else {
+            continue;
+        };
+
+        let redundant = bound_predicate
+            .bounds
+            .iter()
+            .enumerate()
+            .filter(|(_, bound)| {
+                let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound else {
+                    return false;
+                };
+                let Res::Def(DefKind::Trait, trait_id) = poly_trait_ref.trait_ref.path.res else {
+                    return false;
+                };
+                implied(index, trait_id)
+            })
+            .map(|(bound_pos, _)| bound_pos)
+            .collect::<Vec<_>>();
+
+        // The only bounds of the parameter are `Deref` and the trait, so at most one bound is redundant
+        let target = targets[&index];
+        for span in bound_removal_spans(generics, pred_pos, &redundant) {
+            emit(span, target);
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoundImpliedByDerefTarget {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Fn(_, generics, body_id) = item.kind
+            && !item.span.from_expansion()
+        {
+            check_fn(cx, item.owner_id.def_id, generics, body_id);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        // Only lint on inherent methods, trait methods have to match the bounds of the trait.
+        if let ImplItemKind::Fn(_, body_id) = item.kind
+            && trait_ref_of_method(cx, item.owner_id.def_id).is_none()
+            && !item.span.from_expansion()
+        {
+            check_fn(cx, item.owner_id.def_id, item.generics, body_id);
+        }
+    }
+}
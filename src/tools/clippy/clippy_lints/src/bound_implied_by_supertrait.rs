@@ -0,0 +1,225 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::remaining_bounds_sugg;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{GenericBound, Generics, PolyTraitRef, PredicateOrigin, TraitBoundModifier, WherePredicate};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{Clause, OutlivesPredicate, PredicateKind};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+use rustc_trait_selection::traits::supertrait_def_ids;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for trait bounds on generic parameters that are implied by a supertrait of another
+    /// bound on the same parameter, like `B` in `T: A + B` for `trait A: B`. The supertraits of
+    /// the common traits of the standard library are also recognized when they take `Self` as
+    /// an argument: `PartialEq` by `Eq` and `PartialOrd`, and `PartialOrd` by `Ord`. Also checks
+    /// for `'static` bounds implied by a trait with a `'static` supertrait, like `Any`.
+    ///
+    /// ### Why is this bad?
+    /// The bound is already required by the other one, so it only adds noise.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn f<T: Copy + Clone>(x: T) {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f<T: Copy>(x: T) {}
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub BOUND_IMPLIED_BY_SUPERTRAIT,
+    complexity,
+    "trait bounds on generic parameters that are implied by a supertrait of another bound"
+}
+declare_lint_pass!(BoundImpliedBySupertrait => [BOUND_IMPLIED_BY_SUPERTRAIT]);
+
+/// Returns the trait of the bound, if it's a plain bound without generic arguments, like `Clone`
+/// but not `PartialEq<u32>`: a bound `PartialOrd<U>` only implies `PartialEq<U>`.
+fn plain_trait_bound(bound: &GenericBound<'_>) -> Option<DefId> {
+    let GenericBound::Trait(
+        PolyTraitRef {
+            trait_ref,
+            bound_generic_params,
+            ..
+        },
+        TraitBoundModifier::None,
+    ) = bound
+    else {
+        return None;
+    };
+    let Res::Def(DefKind::Trait, trait_id) = trait_ref.path.res else {
+        return None;
+    };
+    let has_args = trait_ref.path.segments.iter().any(|segment| {
+        segment
+            .args
+            .map_or(false, |args| !args.args.is_empty() || !args.bindings.is_empty())
+    });
+    (bound_generic_params.is_empty() && !has_args).then_some(trait_id)
+}
+
+/// Returns the supertraits of the trait that take no generic arguments, transitively, like `B` and
+/// `C` for `trait A: B` and `trait B: C`. The supertraits of a trait from another crate are found
+/// the same way, through the predicates recorded in its metadata.
+fn plain_supertraits(cx: &LateContext<'_>, trait_id: DefId) -> Vec<DefId> {
+    let mut supertraits = Vec::new();
+    let mut stack = vec![trait_id];
+    while let Some(id) = stack.pop() {
+        for (predicate, _) in cx.tcx.super_predicates_of(id).predicates {
+            if let PredicateKind::Clause(Clause::Trait(trait_predicate)) = predicate.kind().skip_binder()
+                && trait_predicate.self_ty().is_param(0)
+                && trait_predicate.trait_ref.substs.len() == 1
+                && !supertraits.contains(&trait_predicate.def_id())
+            {
+                supertraits.push(trait_predicate.def_id());
+                stack.push(trait_predicate.def_id());
+            }
+        }
+    }
+    supertraits
+}
+
+/// Returns the traits implied by the given trait: its plain supertraits, and a curated list of
+/// supertraits in the standard library taking `Self` as an argument, like `PartialEq<Self>`.
+fn implied_traits(cx: &LateContext<'_>, trait_id: DefId) -> Vec<DefId> {
+    let lang_items = cx.tcx.lang_items();
+    let implied = if Some(trait_id) == lang_items.copy_trait() {
+        vec![lang_items.clone_trait()]
+    } else if cx.tcx.is_diagnostic_item(sym::Eq, trait_id) || Some(trait_id) == lang_items.partial_ord_trait() {
+        vec![lang_items.eq_trait()]
+    } else if cx.tcx.is_diagnostic_item(sym::Ord, trait_id) {
+        vec![
+            lang_items.partial_ord_trait(),
+            cx.tcx.get_diagnostic_item(sym::Eq),
+            lang_items.eq_trait(),
+        ]
+    } else {
+        Vec::new()
+    };
+    implied
+        .into_iter()
+        .flatten()
+        .chain(plain_supertraits(cx, trait_id))
+        .collect()
+}
+
+/// Whether the trait requires `Self: 'static`, directly or through one of its supertraits, as in
+/// `trait Any: 'static`.
+fn implies_static(cx: &LateContext<'_>, trait_id: DefId) -> bool {
+    supertrait_def_ids(cx.tcx, trait_id).any(|id| {
+        cx.tcx.super_predicates_of(id).predicates.iter().any(|(predicate, _)| {
+            matches!(
+                predicate.kind().skip_binder(),
+                PredicateKind::Clause(Clause::TypeOutlives(OutlivesPredicate(ty, region)))
+                    if ty.is_param(0) && region.is_static()
+            )
+        })
+    })
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoundImpliedBySupertrait {
+    fn check_generics(&mut self, cx: &LateContext<'tcx>, gen: &'tcx Generics<'tcx>) {
+        if gen.span.from_expansion() {
+            return;
+        }
+
+        let bound_predicates = gen
+            .predicates
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, predicate)| {
+                let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                    return None;
+                };
+                let (param_id, _) = bound_predicate.bounded_ty.as_generic_param()?;
+                (bound_predicate.origin != PredicateOrigin::ImplTrait
+                    && !bound_predicate.span.from_expansion()
+                    && bound_predicate.bound_generic_params.is_empty())
+                .then_some((pos, param_id, bound_predicate))
+            })
+            .collect::<Vec<_>>();
+
+        // The traits implied by the bounds of each parameter, along with the implying trait, collected
+        // from all of its predicates, so that `T: Copy` also implies a `where T: Clone`
+        let mut implied: FxHashMap<(DefId, DefId), DefId> = FxHashMap::default();
+        // The traits implying `'static` for each parameter, whatever their generic arguments
+        let mut implied_static: FxHashMap<DefId, DefId> = FxHashMap::default();
+        for (_, param_id, bound_predicate) in &bound_predicates {
+            for trait_id in bound_predicate.bounds.iter().filter_map(plain_trait_bound) {
+                for implied_id in implied_traits(cx, trait_id) {
+                    implied.entry((*param_id, implied_id)).or_insert(trait_id);
+                }
+            }
+            for bound in bound_predicate.bounds {
+                if let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound
+                    && let Some(trait_id) = poly_trait_ref.trait_ref.trait_def_id()
+                    && implies_static(cx, trait_id)
+                {
+                    implied_static.entry(*param_id).or_insert(trait_id);
+                }
+            }
+        }
+        if implied.is_empty() && implied_static.is_empty() {
+            return;
+        }
+
+        for (pos, param_id, bound_predicate) in bound_predicates {
+            let redundant = bound_predicate
+                .bounds
+                .iter()
+                .enumerate()
+                .filter_map(|(bound_pos, bound)| {
+                    let implying = match bound {
+                        GenericBound::Outlives(lifetime) if lifetime.is_static() => implied_static.get(&param_id)?,
+                        _ => implied.get(&(param_id, plain_trait_bound(bound)?))?,
+                    };
+                    Some((bound_pos, *implying))
+                })
+                .collect::<Vec<_>>();
+
+            match redundant.as_slice() {
+                [] => {},
+                &[(bound_pos, implying)] => span_lint_and_sugg(
+                    cx,
+                    BOUND_IMPLIED_BY_SUPERTRAIT,
+                    gen.span_for_bound_removal(pos, bound_pos),
+                    &format!("this bound is implied by `{}`", cx.tcx.item_name(implying)),
+                    "remove the bound",
+                    String::new(),
+                    Applicability::MachineApplicable,
+                ),
+                _ if redundant.len() == bound_predicate.bounds.len() => span_lint_and_sugg(
+                    cx,
+                    BOUND_IMPLIED_BY_SUPERTRAIT,
+                    gen.span_for_predicate_removal(pos),
+                    "these bounds are implied by supertraits of other bounds",
+                    "remove the bounds",
+                    String::new(),
+                    Applicability::MachineApplicable,
+                ),
+                _ => {
+                    let bound_positions = redundant.iter().map(|&(bound_pos, _)| bound_pos).collect::<Vec<_>>();
+                    let mut applicability = Applicability::MachineApplicable;
+                    let Some((bounds_span, sugg)) =
+                        remaining_bounds_sugg(cx, bound_predicate.bounds, &bound_positions, &mut applicability)
+                    else {
+                        continue;
+                    };
+                    span_lint_and_sugg(
+                        cx,
+                        BOUND_IMPLIED_BY_SUPERTRAIT,
+                        bounds_span,
+                        "these bounds are implied by supertraits of other bounds",
+                        "try",
+                        sugg,
+                        applicability,
+                    );
+                },
+            }
+        }
+    }
+}
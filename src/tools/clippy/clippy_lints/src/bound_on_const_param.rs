@@ -0,0 +1,76 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_ast::ast::{GenericParamKind, Generics, TyKind, WherePredicate};
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for where predicates bounding a const generic parameter of the same generics, as
+    /// in `where N: Clone` for `const N: usize`.
+    ///
+    /// The lint runs before name resolution errors stop the compilation, so that it can point
+    /// out the confusion along with the compiler's "expected type" error.
+    ///
+    /// ### Why is this bad?
+    /// Const parameters are values, only types can have trait bounds, so this doesn't compile.
+    ///
+    /// ### Known problems
+    /// The parameter is only matched by name, a type with the same name as the const parameter
+    /// can be bounded like this.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// fn f<const N: usize>()
+    /// where
+    ///     N: Clone,
+    /// {
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f<const N: usize>() {}
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub BOUND_ON_CONST_PARAM,
+    correctness,
+    "where predicates bounding a const generic parameter"
+}
+declare_lint_pass!(BoundOnConstParam => [BOUND_ON_CONST_PARAM]);
+
+// This can't be part of the late `trait_bounds` pass: the bounded type fails to resolve with
+// E0573, and the analysis stops before the late lint passes run, so only an early pass ever sees
+// the predicate. Early passes have no `Res` though, hence the match by name. The lint is in
+// `correctness` as such a predicate never compiles.
+impl EarlyLintPass for BoundOnConstParam {
+    fn check_generics(&mut self, cx: &EarlyContext<'_>, gen: &Generics) {
+        if gen.span.from_expansion() {
+            return;
+        }
+
+        for predicate in &gen.where_clause.predicates {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            // Names resolve to const parameters only as a single segment without generic arguments
+            if let TyKind::Path(None, path) = &bound_predicate.bounded_ty.kind
+                && let [segment] = &*path.segments
+                && segment.args.is_none()
+                && !bound_predicate.span.from_expansion()
+                && let Some(param) = gen.params.iter().find(|param| {
+                    matches!(param.kind, GenericParamKind::Const { .. }) && param.ident == segment.ident
+                })
+            {
+                span_lint_and_then(
+                    cx,
+                    BOUND_ON_CONST_PARAM,
+                    bound_predicate.bounded_ty.span,
+                    &format!("`{}` is a const parameter, which cannot have trait bounds", segment.ident),
+                    |diag| {
+                        diag.span_note(param.ident.span, "the const parameter is declared here");
+                        diag.help("bound a type parameter instead, or remove the predicate");
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::has_generic_args;
+use clippy_utils::source::snippet_with_applicability;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::{GenericBound, Generics, ItemKind, PolyTraitRef, PredicateOrigin, TraitBoundModifier, WherePredicate};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for trait bounds that contain all the traits of a trait alias defined in the same
+    /// module, like `T: Clone + Send` when the module defines `trait CloneSend = Clone + Send;`.
+    ///
+    /// ### Why is this bad?
+    /// The alias was likely defined to name this set of bounds, writing them out again makes the
+    /// signatures longer and easy to miss when the alias changes.
+    ///
+    /// ### Known problems
+    /// A bound set that happens to match the alias may be unrelated to it, and is meant to
+    /// change independently.
+    ///
+    /// ### Example
+    /// ```rust
+    /// #![feature(trait_alias)]
+    ///
+    /// trait CloneSend = Clone + Send;
+    ///
+    /// fn f<T: Clone + Send>(x: T) {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #![feature(trait_alias)]
+    ///
+    /// trait CloneSend = Clone + Send;
+    ///
+    /// fn f<T: CloneSend>(x: T) {}
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub BOUNDS_MATCHING_TRAIT_ALIAS,
+    pedantic,
+    "trait bounds that could be written with a trait alias defined in the same module"
+}
+impl_lint_pass!(BoundsMatchingTraitAlias => [BOUNDS_MATCHING_TRAIT_ALIAS]);
+
+/// A trait alias without generic parameters which only expands to plain traits, like
+/// `trait CloneSend = Clone + Send;`.
+struct TraitAlias {
+    name: Symbol,
+    module: LocalDefId,
+    traits: Vec<DefId>,
+}
+
+#[derive(Default)]
+pub struct BoundsMatchingTraitAlias {
+    aliases: Vec<TraitAlias>,
+}
+
+/// Returns the trait of the bound, if it's a plain bound without generic arguments or modifiers.
+fn plain_trait_bound(bound: &GenericBound<'_>) -> Option<DefId> {
+    let GenericBound::Trait(
+        PolyTraitRef {
+            trait_ref,
+            bound_generic_params,
+            ..
+        },
+        TraitBoundModifier::None,
+    ) = bound
+    else {
+        return None;
+    };
+    let Res::Def(DefKind::Trait, trait_id) = trait_ref.path.res else {
+        return None;
+    };
+    (bound_generic_params.is_empty() && !has_generic_args(trait_ref.path)).then_some(trait_id)
+}
+
+impl<'tcx> LateLintPass<'tcx> for BoundsMatchingTraitAlias {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for id in cx.tcx.hir().items() {
+            let item = cx.tcx.hir().item(id);
+            if let ItemKind::TraitAlias(generics, bounds) = item.kind
+                && generics.params.is_empty()
+                && generics.predicates.is_empty()
+                && !item.span.from_expansion()
+                && let Some(mut traits) = bounds.iter().map(plain_trait_bound).collect::<Option<Vec<_>>>()
+            {
+                traits.sort_unstable();
+                traits.dedup();
+                // An alias of a single trait is only renaming it
+                if traits.len() > 1 {
+                    self.aliases.push(TraitAlias {
+                        name: item.ident.name,
+                        module: cx.tcx.parent_module_from_def_id(item.owner_id.def_id),
+                        traits,
+                    });
+                }
+            }
+        }
+    }
+
+    fn check_generics(&mut self, cx: &LateContext<'tcx>, gen: &'tcx Generics<'tcx>) {
+        if self.aliases.is_empty() || gen.span.from_expansion() {
+            return;
+        }
+        // The alias is only known to be nameable without a path in its own module
+        let module = cx.tcx.parent_module(cx.last_node_with_lint_attrs);
+
+        for predicate in gen.predicates {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            if bound_predicate.origin == PredicateOrigin::ImplTrait
+                || bound_predicate.span.from_expansion()
+                || !bound_predicate.bound_generic_params.is_empty()
+            {
+                continue;
+            }
+            let traits = bound_predicate.bounds.iter().map(plain_trait_bound).collect::<Vec<_>>();
+            // Prefer the alias collapsing the most bounds
+            let Some(alias) = self
+                .aliases
+                .iter()
+                .filter(|alias| {
+                    alias.module == module
+                        && alias
+                            .traits
+                            .iter()
+                            .all(|trait_id| traits.contains(&Some(*trait_id)))
+                })
+                .max_by_key(|alias| alias.traits.len())
+            else {
+                continue;
+            };
+
+            // The alias takes the place of the first of its traits, other bounds are kept in order
+            let mut applicability = Applicability::MachineApplicable;
+            let mut replaced = Vec::new();
+            let mut sugg = Vec::new();
+            for (bound, trait_id) in bound_predicate.bounds.iter().zip(&traits) {
+                match trait_id {
+                    Some(trait_id) if alias.traits.contains(trait_id) => {
+                        if replaced.is_empty() {
+                            sugg.push(alias.name.to_string());
+                        }
+                        if !replaced.contains(trait_id) {
+                            replaced.push(*trait_id);
+                            continue;
+                        }
+                    },
+                    _ => {},
+                }
+                sugg.push(snippet_with_applicability(cx, bound.span(), "..", &mut applicability).into_owned());
+            }
+            let bounds_span = bound_predicate
+                .bounds
+                .iter()
+                .map(GenericBound::span)
+                .reduce(Span::to)
+                .unwrap_or(bound_predicate.span);
+            span_lint_and_sugg(
+                cx,
+                BOUNDS_MATCHING_TRAIT_ALIAS,
+                bounds_span,
+                &format!("these bounds can be written with the trait alias `{}`", alias.name),
+                "try",
+                sugg.join(" + "),
+                applicability,
+            );
+        }
+    }
+}
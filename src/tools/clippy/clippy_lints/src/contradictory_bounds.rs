@@ -0,0 +1,108 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{GenericBound, Generics, LangItem, PredicateOrigin, TraitBoundModifier, WherePredicate};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for type parameters bound by two traits that no type can implement together, in
+    /// their inline bounds or in the where clause. Currently these are `Copy` and `Drop`. The
+    /// `?Sized` and `Sized` bounds are checked by `contradictory_sized_bounds`.
+    ///
+    /// ### Why is this bad?
+    /// The bounds can't be satisfied, so the item can't be used with any type.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # #![allow(drop_bounds)]
+    /// fn f<T: Copy + Drop>(x: T) {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f<T: Copy>(x: T) {}
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub CONTRADICTORY_BOUNDS,
+    suspicious,
+    "type parameters bound by two traits that no type can implement together"
+}
+declare_lint_pass!(ContradictoryBounds => [CONTRADICTORY_BOUNDS]);
+
+/// Pairs of traits that no type can implement both of.
+const EXCLUSIVE_TRAITS: &[(LangItem, LangItem)] = &[(LangItem::Copy, LangItem::Drop)];
+
+impl<'tcx> LateLintPass<'tcx> for ContradictoryBounds {
+    fn check_generics(&mut self, cx: &LateContext<'tcx>, gen: &'tcx Generics<'tcx>) {
+        if gen.span.from_expansion() {
+            return;
+        }
+
+        // The parameter, trait and span of each trait bound on a type parameter
+        let mut bounds: Vec<(DefId, DefId, Span)> = Vec::new();
+        for predicate in gen.predicates {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            if bound_predicate.origin == PredicateOrigin::ImplTrait
+                || bound_predicate.span.from_expansion()
+                || !bound_predicate.bound_generic_params.is_empty()
+            {
+                continue;
+            }
+            let Some((param_id, _)) = bound_predicate.bounded_ty.as_generic_param() else {
+                continue;
+            };
+            for bound in bound_predicate.bounds {
+                if let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound
+                    && let Some(trait_id) = poly_trait_ref.trait_ref.trait_def_id()
+                {
+                    bounds.push((param_id, trait_id, poly_trait_ref.span));
+                }
+            }
+        }
+
+        for &(first, second) in EXCLUSIVE_TRAITS {
+            let (Some(first), Some(second)) = (cx.tcx.lang_items().get(first), cx.tcx.lang_items().get(second)) else {
+                continue;
+            };
+            for &(param_id, _, first_span) in bounds.iter().filter(|(_, trait_id, _)| *trait_id == first) {
+                let Some(&(_, _, second_span)) = bounds
+                    .iter()
+                    .find(|&&(id, trait_id, _)| id == param_id && trait_id == second)
+                else {
+                    continue;
+                };
+                // Point at the bound that comes last, it's the one that makes the bounds contradictory
+                let ((span, span_trait), (note_span, note_trait)) = if first_span.lo() < second_span.lo() {
+                    ((second_span, second), (first_span, first))
+                } else {
+                    ((first_span, first), (second_span, second))
+                };
+                span_lint_and_then(
+                    cx,
+                    CONTRADICTORY_BOUNDS,
+                    span,
+                    &format!(
+                        "`{}` is bound by both `{}` and `{}`",
+                        cx.tcx.item_name(param_id),
+                        cx.tcx.item_name(note_trait),
+                        cx.tcx.item_name(span_trait),
+                    ),
+                    |diag| {
+                        diag.span_note(
+                            note_span,
+                            format!("the `{}` bound is here", cx.tcx.item_name(note_trait)),
+                        );
+                        diag.help(format!(
+                            "no type can implement both `{}` and `{}`, so the bounds can't be satisfied",
+                            cx.tcx.item_name(first),
+                            cx.tcx.item_name(second),
+                        ));
+                    },
+                );
+            }
+        }
+    }
+}
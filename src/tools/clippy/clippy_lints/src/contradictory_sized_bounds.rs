@@ -0,0 +1,130 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{GenericBound, Generics, PredicateOrigin, TraitBoundModifier, WherePredicate};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for type parameters bound by both `?Sized` and `Sized`, in their inline bounds or
+    /// in the where clause.
+    ///
+    /// ### Why is this bad?
+    /// The `Sized` bound wins, so the parameter is still required to be sized. The `?Sized`
+    /// bound suggests otherwise, and one of the two bounds is most likely a mistake.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn f<T: ?Sized>(x: &T)
+    /// where
+    ///     T: Sized,
+    /// {
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f<T: ?Sized>(x: &T) {}
+    /// ```
+    /// Or, if the parameter has to be sized:
+    /// ```rust
+    /// fn f<T>(x: &T) {}
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub CONTRADICTORY_SIZED_BOUNDS,
+    correctness,
+    "type parameters bound by both `?Sized` and `Sized`"
+}
+declare_lint_pass!(ContradictorySizedBounds => [CONTRADICTORY_SIZED_BOUNDS]);
+
+/// The position of a `Sized` or `?Sized` bound in the predicates of the generics.
+struct SizedBound {
+    param_id: DefId,
+    pred_pos: usize,
+    bound_pos: usize,
+    /// Whether it's the only bound of its predicate, in which case the whole predicate is removed
+    alone: bool,
+    maybe: bool,
+}
+
+impl SizedBound {
+    fn removal_span(&self, gen: &Generics<'_>) -> Span {
+        if self.alone {
+            gen.span_for_predicate_removal(self.pred_pos)
+        } else {
+            gen.span_for_bound_removal(self.pred_pos, self.bound_pos)
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for ContradictorySizedBounds {
+    fn check_generics(&mut self, cx: &LateContext<'tcx>, gen: &'tcx Generics<'tcx>) {
+        let Some(sized_trait) = cx.tcx.lang_items().sized_trait() else {
+            return;
+        };
+        if gen.span.from_expansion() {
+            return;
+        }
+
+        let mut sized_bounds = Vec::new();
+        for (pred_pos, predicate) in gen.predicates.iter().enumerate() {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            if bound_predicate.origin == PredicateOrigin::ImplTrait
+                || bound_predicate.span.from_expansion()
+                || !bound_predicate.bound_generic_params.is_empty()
+            {
+                continue;
+            }
+            let Some((param_id, _)) = bound_predicate.bounded_ty.as_generic_param() else {
+                continue;
+            };
+            for (bound_pos, bound) in bound_predicate.bounds.iter().enumerate() {
+                if let GenericBound::Trait(poly_trait_ref, modifier) = bound
+                    && matches!(modifier, TraitBoundModifier::None | TraitBoundModifier::Maybe)
+                    && poly_trait_ref.trait_ref.trait_def_id() == Some(sized_trait)
+                {
+                    sized_bounds.push(SizedBound {
+                        param_id,
+                        pred_pos,
+                        bound_pos,
+                        alone: bound_predicate.bounds.len() == 1,
+                        maybe: *modifier == TraitBoundModifier::Maybe,
+                    });
+                }
+            }
+        }
+
+        for maybe_sized in sized_bounds.iter().filter(|bound| bound.maybe) {
+            let Some(sized) = sized_bounds
+                .iter()
+                .find(|bound| !bound.maybe && bound.param_id == maybe_sized.param_id)
+            else {
+                continue;
+            };
+            let sized_span = gen.predicates[sized.pred_pos].bounds()[sized.bound_pos].span();
+            span_lint_and_then(
+                cx,
+                CONTRADICTORY_SIZED_BOUNDS,
+                maybe_sized.removal_span(gen),
+                &format!(
+                    "`{}` is bound by both `?Sized` and `Sized`",
+                    cx.tcx.item_name(maybe_sized.param_id)
+                ),
+                |diag| {
+                    // `Sized` wins, so removing `?Sized` keeps the meaning of the code
+                    diag.span_suggestion(
+                        maybe_sized.removal_span(gen),
+                        "remove the `?Sized` bound",
+                        "",
+                        Applicability::MachineApplicable,
+                    );
+                    diag.span_note(sized_span, "the parameter is required to be sized by this bound");
+                    diag.help("to allow unsized types, remove the `Sized` bound instead");
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use rustc_errors::Applicability;
+use rustc_hir::{Generics, TyKind, WherePredicate};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for predicates in where clauses bounding the unit type or the never type, as in
+    /// `where (): Clone`.
+    ///
+    /// ### Why is this bad?
+    /// The predicate doesn't involve any of the generic parameters, so it either always holds or
+    /// never does. Such predicates are usually left over from code generated by a macro.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn f()
+    /// where
+    ///     (): Clone,
+    /// {
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f() {}
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub DEGENERATE_WHERE_BOUND,
+    complexity,
+    "predicates in where clauses bounding `()` or `!`"
+}
+declare_lint_pass!(DegenerateWhereBound => [DEGENERATE_WHERE_BOUND]);
+
+impl<'tcx> LateLintPass<'tcx> for DegenerateWhereBound {
+    fn check_generics(&mut self, cx: &LateContext<'tcx>, gen: &'tcx Generics<'tcx>) {
+        if gen.span.from_expansion() {
+            return;
+        }
+        // Predicates that don't hold are only allowed with `trivial_bounds`, where removing them
+        // changes the meaning of the item
+        let applicability = if cx.tcx.features().trivial_bounds {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        };
+
+        for (pos, predicate) in gen.predicates.iter().enumerate() {
+            if let WherePredicate::BoundPredicate(bound_predicate) = predicate
+                && predicate.in_where_clause()
+                && !bound_predicate.span.from_expansion()
+            {
+                let ty = match bound_predicate.bounded_ty.kind {
+                    TyKind::Tup([]) => "the unit type",
+                    TyKind::Never => "the never type",
+                    _ => continue,
+                };
+                span_lint_and_sugg(
+                    cx,
+                    DEGENERATE_WHERE_BOUND,
+                    gen.span_for_predicate_removal(pos),
+                    &format!("this predicate bounds {ty}"),
+                    "remove the predicate",
+                    String::new(),
+                    applicability,
+                );
+            }
+        }
+    }
+}
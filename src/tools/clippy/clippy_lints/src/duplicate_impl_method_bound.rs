@@ -0,0 +1,182 @@
+use clippy_utils::diagnostics::{span_lint_and_note, span_lint_and_remove_bounds};
+use clippy_utils::SpanlessEq;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{
+    GenericBound, Generics, ImplItem, ImplItemKind, Item, ItemKind, Node, PathSegment, PredicateOrigin,
+    TraitBoundModifier, WherePredicate,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for trait bounds on the generic parameters of an impl which are repeated in the
+    /// where clause of a method of the impl, including impls and methods generated by macros.
+    ///
+    /// A removal is only suggested when the method, the impl and both bounds are written in
+    /// the source. If any of them comes from a macro expansion, the lint only points out the
+    /// duplication: the macro may generate the impl with other bounds elsewhere, or the bound
+    /// may come from tokens shared with other generated items.
+    ///
+    /// ### Why is this bad?
+    /// The bound is already required by the impl, so repeating it on the method only adds
+    /// noise.
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct Wrap<T>(T);
+    ///
+    /// impl<T: Clone> Wrap<T> {
+    ///     fn duplicate(&self) -> (T, T)
+    ///     where
+    ///         T: Clone,
+    ///     {
+    ///         (self.0.clone(), self.0.clone())
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Wrap<T>(T);
+    ///
+    /// impl<T: Clone> Wrap<T> {
+    ///     fn duplicate(&self) -> (T, T) {
+    ///         (self.0.clone(), self.0.clone())
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub DUPLICATE_IMPL_METHOD_BOUND,
+    nursery,
+    "trait bounds on the generic parameters of an impl repeated on one of its methods"
+}
+declare_lint_pass!(DuplicateImplMethodBound => [DUPLICATE_IMPL_METHOD_BOUND]);
+
+/// A trait bound declared on a generic parameter of the impl.
+struct ImplBound<'tcx> {
+    param_id: DefId,
+    trait_res: Res,
+    segments: &'tcx [PathSegment<'tcx>],
+    span: Span,
+}
+
+/// Returns the bounded parameter and the plain trait bounds of the bound predicates of the
+/// generics, skipping `?Sized` and higher-ranked bounds.
+fn impl_bounds<'tcx>(generics: &'tcx Generics<'tcx>) -> Vec<ImplBound<'tcx>> {
+    let mut bounds = Vec::new();
+    for predicate in generics.predicates {
+        if let WherePredicate::BoundPredicate(bound_predicate) = predicate
+            && bound_predicate.origin != PredicateOrigin::ImplTrait
+            && bound_predicate.bound_generic_params.is_empty()
+            && let Some((param_id, _)) = bound_predicate.bounded_ty.as_generic_param()
+        {
+            for bound in bound_predicate.bounds {
+                if let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound
+                    && poly_trait_ref.bound_generic_params.is_empty()
+                {
+                    bounds.push(ImplBound {
+                        param_id,
+                        trait_res: poly_trait_ref.trait_ref.path.res,
+                        segments: poly_trait_ref.trait_ref.path.segments,
+                        span: bound.span(),
+                    });
+                }
+            }
+        }
+    }
+    bounds
+}
+
+impl<'tcx> LateLintPass<'tcx> for DuplicateImplMethodBound {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        if !matches!(item.kind, ImplItemKind::Fn(..)) || in_external_macro(cx.sess(), item.span) {
+            return;
+        }
+        let parent = cx.tcx.hir().get_parent_item(item.hir_id());
+        let Some(Node::Item(Item {
+            kind: ItemKind::Impl(impl_),
+            span: impl_span,
+            ..
+        })) = cx.tcx.hir().find_by_def_id(parent.def_id)
+        else {
+            return;
+        };
+
+        let impl_bounds = impl_bounds(impl_.generics);
+        if impl_bounds.is_empty() {
+            return;
+        }
+
+        for (pred_pos, predicate) in item.generics.predicates.iter().enumerate() {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            if bound_predicate.origin == PredicateOrigin::ImplTrait || !bound_predicate.bound_generic_params.is_empty()
+            {
+                continue;
+            }
+            let Some((param_id, _)) = bound_predicate.bounded_ty.as_generic_param() else {
+                continue;
+            };
+
+            let duplicated = bound_predicate
+                .bounds
+                .iter()
+                .enumerate()
+                .filter_map(|(bound_pos, bound)| {
+                    let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound else {
+                        return None;
+                    };
+                    if !poly_trait_ref.bound_generic_params.is_empty() {
+                        return None;
+                    }
+                    let trait_path = poly_trait_ref.trait_ref.path;
+                    let impl_bound = impl_bounds.iter().find(|impl_bound| {
+                        impl_bound.param_id == param_id
+                            && impl_bound.trait_res == trait_path.res
+                            && SpanlessEq::new(cx).eq_path_segments(impl_bound.segments, trait_path.segments)
+                    })?;
+                    Some((bound_pos, bound.span(), impl_bound.span))
+                })
+                .collect::<Vec<_>>();
+            if duplicated.is_empty() {
+                continue;
+            }
+
+            // Every span involved in the removal has to be written by the user, otherwise the edit
+            // could land inside a macro definition, or the impl bound could change with the macro
+            let synthetic = item.span.from_expansion()
+                || impl_span.from_expansion()
+                || bound_predicate.span.from_expansion()
+                || duplicated
+                    .iter()
+                    .any(|(_, span, impl_bound_span)| span.from_expansion() || impl_bound_span.from_expansion());
+            if synthetic {
+                for (_, span, impl_bound_span) in duplicated {
+                    span_lint_and_note(
+                        cx,
+                        DUPLICATE_IMPL_METHOD_BOUND,
+                        span,
+                        "this bound is already declared on the impl",
+                        Some(impl_bound_span),
+                        "the bound is declared here, but no fix is suggested as it involves a macro expansion",
+                    );
+                }
+                continue;
+            }
+
+            let bound_positions = duplicated.iter().map(|&(bound_pos, ..)| bound_pos).collect::<Vec<_>>();
+            span_lint_and_remove_bounds(
+                cx,
+                DUPLICATE_IMPL_METHOD_BOUND,
+                item.generics,
+                pred_pos,
+                &bound_positions,
+                "this bound is already declared on the impl",
+            );
+        }
+    }
+}
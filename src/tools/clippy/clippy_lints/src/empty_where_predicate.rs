@@ -0,0 +1,57 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use rustc_errors::Applicability;
+use rustc_hir::{Generics, WherePredicate};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for predicates in where clauses with an empty list of bounds, as in `where T:`.
+    ///
+    /// ### Why is this bad?
+    /// The predicate doesn't require anything, so it only adds noise.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn f<T>(x: T)
+    /// where
+    ///     T:,
+    /// {
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f<T>(x: T) {}
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub EMPTY_WHERE_PREDICATE,
+    style,
+    "predicates in where clauses without any bounds"
+}
+declare_lint_pass!(EmptyWherePredicate => [EMPTY_WHERE_PREDICATE]);
+
+impl<'tcx> LateLintPass<'tcx> for EmptyWherePredicate {
+    fn check_generics(&mut self, cx: &LateContext<'tcx>, gen: &'tcx Generics<'tcx>) {
+        if gen.span.from_expansion() {
+            return;
+        }
+
+        for (pos, predicate) in gen.predicates.iter().enumerate() {
+            if let WherePredicate::BoundPredicate(bound_predicate) = predicate
+                && predicate.in_where_clause()
+                && bound_predicate.bounds.is_empty()
+                && !bound_predicate.span.from_expansion()
+            {
+                span_lint_and_sugg(
+                    cx,
+                    EMPTY_WHERE_PREDICATE,
+                    gen.span_for_predicate_removal(pos),
+                    "this predicate has no bounds",
+                    "remove the predicate",
+                    String::new(),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{bound_removal_spans, is_lint_allowed};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{GenericBound, Generics, Item, ItemKind, PredicateOrigin, TraitBoundModifier, WherePredicate};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for explicit `Sized` bounds on the type parameters of struct, enum and union
+    /// definitions.
+    ///
+    /// ### Why is this bad?
+    /// Type parameters are `Sized` unless they are bound by `?Sized`, so the bound only adds noise.
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct S<T: Sized> {
+    ///     x: T,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct S<T> {
+    ///     x: T,
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub EXPLICIT_SIZED_BOUND,
+    complexity,
+    "explicit `Sized` bounds on the type parameters of data type definitions"
+}
+declare_lint_pass!(ExplicitSizedBound => [EXPLICIT_SIZED_BOUND]);
+
+impl<'tcx> LateLintPass<'tcx> for ExplicitSizedBound {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        let (ItemKind::Struct(_, generics) | ItemKind::Enum(_, generics) | ItemKind::Union(_, generics)) = item.kind
+        else {
+            return;
+        };
+        let Some(sized_trait) = cx.tcx.lang_items().sized_trait() else {
+            return;
+        };
+        if item.span.from_expansion() {
+            return;
+        }
+
+        for (pred_pos, predicate) in generics.predicates.iter().enumerate() {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            if bound_predicate.origin == PredicateOrigin::ImplTrait
+                || bound_predicate.span.from_expansion()
+                || !bound_predicate.bound_generic_params.is_empty()
+            {
+                continue;
+            }
+            let Some((param_id, _)) = bound_predicate.bounded_ty.as_generic_param() else {
+                continue;
+            };
+            // `Sized` together with `?Sized` is left to `CONTRADICTORY_SIZED_BOUNDS`, which suggests
+            // removing one of them
+            if is_maybe_sized(generics, param_id) {
+                continue;
+            }
+            let sized_positions = bound_predicate
+                .bounds
+                .iter()
+                .enumerate()
+                .filter(|(_, bound)| {
+                    matches!(bound, GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None)
+                        if poly_trait_ref.trait_ref.trait_def_id() == Some(sized_trait))
+                })
+                .map(|(bound_pos, _)| bound_pos)
+                .collect::<Vec<_>>();
+            if sized_positions.is_empty() {
+                continue;
+            }
+            // Repeated `Sized` bounds are left to `TRAIT_DUPLICATION_IN_BOUNDS` if it's enabled, so
+            // that only one of the lints fires
+            if sized_positions.len() > 1 && !is_lint_allowed(cx, TRAIT_DUPLICATION_IN_BOUNDS, item.hir_id()) {
+                continue;
+            }
+
+            // All the `Sized` bounds are removed, along with the predicate if nothing else is left
+            let removals = bound_removal_spans(generics, pred_pos, &sized_positions);
+            let Some(span) = removals.iter().copied().reduce(Span::to) else {
+                continue;
+            };
+            span_lint_and_then(
+                cx,
+                EXPLICIT_SIZED_BOUND,
+                span,
+                "type parameters are `Sized` by default",
+                |diag| {
+                    let msg = if removals.len() == 1 {
+                        "remove the bound"
+                    } else {
+                        "remove the bounds"
+                    };
+                    diag.multipart_suggestion(
+                        msg,
+                        removals.iter().map(|&span| (span, String::new())).collect(),
+                        Applicability::MachineApplicable,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Checks if the type parameter is bound by `?Sized` in any of the predicates.
+fn is_maybe_sized(generics: &Generics<'_>, param_id: DefId) -> bool {
+    generics.predicates.iter().any(|predicate| {
+        matches!(predicate, WherePredicate::BoundPredicate(bound_predicate)
+            if bound_predicate.bounded_ty.as_generic_param().map(|(id, _)| id) == Some(param_id)
+                && bound_predicate
+                    .bounds
+                    .iter()
+                    .any(|bound| matches!(bound, GenericBound::Trait(_, TraitBoundModifier::Maybe))))
+    })
+}
@@ -0,0 +1,127 @@
+use clippy_utils::diagnostics::span_lint_and_remove_bounds;
+use clippy_utils::has_generic_args;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::{implements_trait_with_env, is_type_diagnostic_item, param_env_without};
+use rustc_hir::{GenericBound, Generics, PredicateOrigin, TraitBoundModifier, WherePredicate};
+use rustc_hir_analysis::hir_ty_to_ty;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Clause, PredicateKind, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for bounds in where clauses on `Box<T>`, `Rc<T>` or `Arc<T>`, when the same bound
+    /// on `T` is specified and implies it, as in `where T: Send, Box<T>: Send`.
+    ///
+    /// ### Why is this bad?
+    /// The bound on the smart pointer always holds when the bound on `T` does, so it only adds
+    /// noise to the signature.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn spawn<T>(value: T)
+    /// where
+    ///     T: Send,
+    ///     Box<T>: Send,
+    /// {
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn spawn<T>(value: T)
+    /// where
+    ///     T: Send,
+    /// {
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub IMPLIED_SMART_POINTER_BOUNDS,
+    complexity,
+    "bounds on `Box<T>`, `Rc<T>` or `Arc<T>` implied by the same bound on `T`"
+}
+declare_lint_pass!(ImpliedSmartPointerBounds => [IMPLIED_SMART_POINTER_BOUNDS]);
+
+/// Gets the type the smart pointer points to, if it's a type parameter.
+fn pointee<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    let ty::Adt(_, substs) = *ty.kind() else {
+        return None;
+    };
+    let pointee = substs.types().next()?;
+    (matches!(pointee.kind(), ty::Param(_))
+        && (ty.is_box() || is_type_diagnostic_item(cx, ty, sym::Rc) || is_type_diagnostic_item(cx, ty, sym::Arc)))
+    .then_some(pointee)
+}
+
+impl<'tcx> LateLintPass<'tcx> for ImpliedSmartPointerBounds {
+    fn check_generics(&mut self, cx: &LateContext<'tcx>, gen: &'tcx Generics<'tcx>) {
+        if gen.span.from_expansion() {
+            return;
+        }
+        // The generics are checked inside of the item they belong to
+        let param_env = cx.tcx.param_env(cx.last_node_with_lint_attrs.owner);
+
+        for (pred_pos, predicate) in gen.predicates.iter().enumerate() {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            if bound_predicate.origin != PredicateOrigin::WhereClause
+                || bound_predicate.span.from_expansion()
+                || !bound_predicate.bound_generic_params.is_empty()
+            {
+                continue;
+            }
+            let ty = hir_ty_to_ty(cx.tcx, bound_predicate.bounded_ty);
+            let Some(pointee) = pointee(cx, ty) else {
+                continue;
+            };
+            // Whether the smart pointer implements the trait without the bounds on it
+            let pointer_env = param_env_without(cx.tcx, param_env, |trait_predicate| trait_predicate.self_ty() == ty);
+            // The whole trait ref is compared, `T: PartialEq<u8>` doesn't imply `Box<T>: PartialEq`
+            let has_pointee_bound = |trait_id| {
+                let pointee_ref = ty::TraitRef::new(cx.tcx, trait_id, [pointee]);
+                param_env.caller_bounds().iter().any(|predicate| {
+                    matches!(
+                        predicate.kind().skip_binder(),
+                        PredicateKind::Clause(Clause::Trait(predicate)) if predicate.trait_ref == pointee_ref
+                    )
+                })
+            };
+
+            let redundant = bound_predicate
+                .bounds
+                .iter()
+                .enumerate()
+                .filter(|(_, bound)| {
+                    let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound else {
+                        return false;
+                    };
+                    // Only plain traits, which are the same trait when bounding `T`. Traits with defaulted parameters,
+                    // like `PartialEq`, would need their defaults as arguments
+                    let has_args = has_generic_args(poly_trait_ref.trait_ref.path);
+                    let Some(trait_id) = poly_trait_ref.trait_ref.trait_def_id() else {
+                        return false;
+                    };
+                    !has_args
+                        && poly_trait_ref.bound_generic_params.is_empty()
+                        && cx.tcx.generics_of(trait_id).count() == 1
+                        && has_pointee_bound(trait_id)
+                        && implements_trait_with_env(cx.tcx, pointer_env, ty, trait_id, [])
+                })
+                .map(|(bound_pos, _)| bound_pos)
+                .collect::<Vec<_>>();
+
+            span_lint_and_remove_bounds(
+                cx,
+                IMPLIED_SMART_POINTER_BOUNDS,
+                gen,
+                pred_pos,
+                &redundant,
+                &format!(
+                    "this bound on `{}` is implied by the same bound on `{pointee}`",
+                    snippet(cx, bound_predicate.bounded_ty.span, ".."),
+                ),
+            );
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_opt;
+use rustc_errors::Applicability;
+use rustc_hir::{
+    GenericBound, GenericParamKind, Generics, ImplItem, ImplItemKind, Item, ItemKind, PredicateOrigin, TraitItem,
+    TraitItemKind, WherePredicate,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{BytePos, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions whose where clause holds a single short predicate bounding a type
+    /// parameter without inline bounds, like `where T: Clone`.
+    ///
+    /// The maximum length of the predicate can be configured with
+    /// `max-inlinable-where-predicate-length`.
+    ///
+    /// ### Why is this bad?
+    /// This is a style preference, a short bound reads better next to the parameter it bounds
+    /// than in a separate where clause.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn f<T>(x: T)
+    /// where
+    ///     T: Clone,
+    /// {
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f<T: Clone>(x: T) {}
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub INLINABLE_WHERE_CLAUSE,
+    restriction,
+    "where clauses with a single short predicate that could be written inline"
+}
+impl_lint_pass!(InlinableWhereClause => [INLINABLE_WHERE_CLAUSE]);
+
+pub struct InlinableWhereClause {
+    max_predicate_len: u64,
+}
+
+impl InlinableWhereClause {
+    pub fn new(max_predicate_len: u64) -> Self {
+        Self { max_predicate_len }
+    }
+
+    fn check_fn(&self, cx: &LateContext<'_>, gen: &Generics<'_>) {
+        if gen.span.from_expansion() || gen.where_clause_span.from_expansion() {
+            return;
+        }
+        let [predicate] = gen
+            .predicates
+            .iter()
+            .filter(|predicate| predicate.in_where_clause())
+            .collect::<Vec<_>>()[..]
+        else {
+            return;
+        };
+        let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+            return;
+        };
+        if !bound_predicate.bound_generic_params.is_empty() || bound_predicate.span.from_expansion() {
+            return;
+        }
+        let Some((param_id, _)) = bound_predicate.bounded_ty.as_generic_param() else {
+            return;
+        };
+        // Only parameters declared by the function itself can take the bound, and only when they
+        // don't have inline bounds of their own
+        let Some(param) = gen.params.iter().find(|param| param.def_id.to_def_id() == param_id) else {
+            return;
+        };
+        if !matches!(param.kind, GenericParamKind::Type { synthetic: false, .. })
+            || param.colon_span.is_some()
+            || gen.predicates.iter().any(|predicate| {
+                matches!(predicate, WherePredicate::BoundPredicate(p)
+                    if p.origin == PredicateOrigin::GenericParam
+                        && p.bounded_ty.as_generic_param().map(|(id, _)| id) == Some(param_id))
+            })
+        {
+            return;
+        }
+
+        let Some(predicate_snippet) = snippet_opt(cx, bound_predicate.span) else {
+            return;
+        };
+        if predicate_snippet.chars().count() as u64 > self.max_predicate_len {
+            return;
+        }
+        let Some(bounds_span) = bound_predicate
+            .bounds
+            .iter()
+            .map(GenericBound::span)
+            .reduce(Span::to)
+        else {
+            return;
+        };
+        let Some(bounds_snippet) = snippet_opt(cx, bounds_span) else {
+            return;
+        };
+        let Some((removal_span, replacement)) = where_clause_removal(cx, gen.where_clause_span) else {
+            return;
+        };
+
+        span_lint_and_then(
+            cx,
+            INLINABLE_WHERE_CLAUSE,
+            bound_predicate.span,
+            "this where clause can be written inline",
+            |diag| {
+                diag.multipart_suggestion(
+                    "move the bound to the parameter",
+                    vec![
+                        (param.name.ident().span.shrink_to_hi(), format!(": {bounds_snippet}")),
+                        (removal_span, replacement.to_string()),
+                    ],
+                    Applicability::MachineApplicable,
+                );
+            },
+        );
+    }
+}
+
+/// Returns the span covering the where clause and the whitespace around it, with the text to
+/// replace it with so that the body is separated from the signature by a single space.
+fn where_clause_removal(cx: &LateContext<'_>, where_clause_span: Span) -> Option<(Span, &'static str)> {
+    let source_map = cx.sess().source_map();
+    let prev = source_map.span_to_prev_source(where_clause_span).ok()?;
+    let next = source_map.span_to_next_source(where_clause_span).ok()?;
+    let prev_ws = prev.len() - prev.trim_end().len();
+    let next_ws = next.len() - next.trim_start().len();
+    let span = where_clause_span
+        .with_lo(where_clause_span.lo() - BytePos(prev_ws as u32))
+        .with_hi(where_clause_span.hi() + BytePos(next_ws as u32));
+    let replacement = if next.trim_start().starts_with('{') { " " } else { "" };
+    Some((span, replacement))
+}
+
+impl<'tcx> LateLintPass<'tcx> for InlinableWhereClause {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Fn(_, generics, _) = item.kind
+            && !item.span.from_expansion()
+        {
+            self.check_fn(cx, generics);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        if let ImplItemKind::Fn(..) = item.kind
+            && !item.span.from_expansion()
+        {
+            self.check_fn(cx, item.generics);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'tcx>) {
+        if let TraitItemKind::Fn(..) = item.kind
+            && !item.span.from_expansion()
+        {
+            self.check_fn(cx, item.generics);
+        }
+    }
+}
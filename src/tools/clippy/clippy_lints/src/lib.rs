@@ -80,6 +80,11 @@
 mod bool_to_int_with_if;
 mod booleans;
 mod borrow_deref_ref;
+mod bound_implied_by_blanket_impl;
+mod bound_implied_by_deref_target;
+mod bound_implied_by_supertrait;
+mod bound_on_const_param;
+mod bounds_matching_trait_alias;
 mod box_default;
 mod cargo;
 mod casts;
@@ -88,6 +93,8 @@
 mod collapsible_if;
 mod collection_is_never_read;
 mod comparison_chain;
+mod contradictory_bounds;
+mod contradictory_sized_bounds;
 mod copies;
 mod copy_iterator;
 mod crate_in_macro_def;
@@ -98,6 +105,7 @@
 mod default_instead_of_iter_empty;
 mod default_numeric_fallback;
 mod default_union_representation;
+mod degenerate_where_bound;
 mod dereference;
 mod derivable_impls;
 mod derive;
@@ -109,11 +117,13 @@
 mod doc;
 mod double_parens;
 mod drop_forget_ref;
+mod duplicate_impl_method_bound;
 mod duplicate_mod;
 mod else_if_without_else;
 mod empty_drop;
 mod empty_enum;
 mod empty_structs_with_brackets;
+mod empty_where_predicate;
 mod entry;
 mod enum_clike;
 mod enum_variants;
@@ -123,6 +133,7 @@
 mod excessive_bools;
 mod exhaustive_items;
 mod exit;
+mod explicit_sized_bound;
 mod explicit_write;
 mod extra_unused_type_parameters;
 mod fallible_impl_from;
@@ -146,6 +157,7 @@
 mod implicit_return;
 mod implicit_saturating_add;
 mod implicit_saturating_sub;
+mod implied_smart_pointer_bounds;
 mod inconsistent_struct_constructor;
 mod index_refutable_slice;
 mod indexing_slicing;
@@ -153,6 +165,7 @@
 mod inherent_impl;
 mod inherent_to_string;
 mod init_numbered_fields;
+mod inlinable_where_clause;
 mod inline_fn_without_body;
 mod instant_subtraction;
 mod int_plus_one;
@@ -222,6 +235,8 @@
 mod needless_parens_on_range_literals;
 mod needless_pass_by_value;
 mod needless_question_mark;
+mod needless_ref_clone_bound;
+mod needless_self_sized_bound;
 mod needless_update;
 mod neg_cmp_op_on_partial_ord;
 mod neg_multiply;
@@ -257,17 +272,22 @@
 mod rc_clone_in_vec_init;
 mod read_zero_byte_vec;
 mod redundant_async_block;
+mod redundant_bound_parens;
 mod redundant_clone;
 mod redundant_closure_call;
+mod redundant_concrete_self_bound;
 mod redundant_else;
 mod redundant_field_names;
+mod redundant_impl_supertrait_bound;
 mod redundant_pub_crate;
+mod redundant_self_supertrait_bound;
 mod redundant_slicing;
 mod redundant_static_lifetimes;
 mod ref_option_ref;
 mod ref_patterns;
 mod reference;
 mod regex;
+mod repeated_inherent_impl_bounds;
 mod return_self_not_must_use;
 mod returns;
 mod same_name_method;
@@ -279,6 +299,7 @@
 mod significant_drop_tightening;
 mod single_char_lifetime_names;
 mod single_component_path_imports;
+mod single_use_type_param;
 mod size_of_in_element_count;
 mod size_of_ref;
 mod slow_vector_initialization;
@@ -292,6 +313,7 @@
 mod swap;
 mod swap_ptr_to_ref;
 mod tabs_in_doc_comments;
+mod tautological_bounds;
 mod temporary_assignment;
 mod tests_outside_test_module;
 mod to_digit_is_some;
@@ -313,10 +335,12 @@
 mod unnested_or_patterns;
 mod unsafe_removed_from_name;
 mod unused_async;
+mod unused_fn_bounds;
 mod unused_io_amount;
 mod unused_peekable;
 mod unused_rounding;
 mod unused_self;
+mod unused_trait_bound;
 mod unused_unit;
 mod unwrap;
 mod unwrap_in_result;
@@ -990,6 +1014,39 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|_| Box::new(items_after_test_module::ItemsAfterTestModule));
     store.register_early_pass(|| Box::new(ref_patterns::RefPatterns));
     store.register_late_pass(|_| Box::new(default_constructed_unit_structs::DefaultConstructedUnitStructs));
+    store.register_late_pass(|_| Box::new(needless_self_sized_bound::NeedlessSelfSizedBound));
+    store.register_late_pass(move |_| {
+        Box::new(single_use_type_param::SingleUseTypeParam::new(
+            avoid_breaking_exported_api,
+        ))
+    });
+    store.register_late_pass(|_| Box::new(bound_implied_by_blanket_impl::BoundImpliedByBlanketImpl));
+    store.register_late_pass(|_| Box::new(unused_trait_bound::UnusedTraitBound));
+    store.register_late_pass(|_| Box::new(bound_implied_by_supertrait::BoundImpliedBySupertrait));
+    store.register_late_pass(|_| Box::new(empty_where_predicate::EmptyWherePredicate));
+    store.register_late_pass(|_| Box::new(needless_ref_clone_bound::NeedlessRefCloneBound));
+    store.register_late_pass(|_| Box::new(unused_fn_bounds::UnusedFnBounds));
+    store.register_late_pass(|_| Box::new(redundant_impl_supertrait_bound::RedundantImplSupertraitBound));
+    store.register_late_pass(|_| Box::new(bound_implied_by_deref_target::BoundImpliedByDerefTarget));
+    store.register_early_pass(|| Box::new(redundant_bound_parens::RedundantBoundParens));
+    store.register_late_pass(|_| Box::new(explicit_sized_bound::ExplicitSizedBound));
+    store.register_late_pass(|_| Box::new(redundant_self_supertrait_bound::RedundantSelfSupertraitBound));
+    store.register_late_pass(|_| Box::new(duplicate_impl_method_bound::DuplicateImplMethodBound));
+    store.register_late_pass(|_| Box::new(redundant_concrete_self_bound::RedundantConcreteSelfBound));
+    store.register_late_pass(|_| Box::new(contradictory_sized_bounds::ContradictorySizedBounds));
+    store.register_late_pass(|_| Box::<bounds_matching_trait_alias::BoundsMatchingTraitAlias>::default());
+    store.register_early_pass(|| Box::new(bound_on_const_param::BoundOnConstParam));
+    let max_inlinable_where_predicate_length = conf.max_inlinable_where_predicate_length;
+    store.register_late_pass(move |_| {
+        Box::new(inlinable_where_clause::InlinableWhereClause::new(
+            max_inlinable_where_predicate_length,
+        ))
+    });
+    store.register_late_pass(|_| Box::new(degenerate_where_bound::DegenerateWhereBound));
+    store.register_late_pass(|_| Box::new(repeated_inherent_impl_bounds::RepeatedInherentImplBounds));
+    store.register_late_pass(|_| Box::new(contradictory_bounds::ContradictoryBounds));
+    store.register_late_pass(|_| Box::new(tautological_bounds::TautologicalBounds));
+    store.register_late_pass(|_| Box::new(implied_smart_pointer_bounds::ImpliedSmartPointerBounds));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
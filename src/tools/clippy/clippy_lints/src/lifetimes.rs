@@ -1,11 +1,11 @@
-use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_then};
 use clippy_utils::trait_ref_of_method;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::nested_filter::{self as hir_nested_filter, NestedFilter};
 use rustc_hir::intravisit::{
-    walk_fn_decl, walk_generic_param, walk_generics, walk_impl_item_ref, walk_item, walk_param_bound,
-    walk_poly_trait_ref, walk_trait_ref, walk_ty, Visitor,
+    walk_body, walk_fn_decl, walk_generic_param, walk_generics, walk_impl_item_ref, walk_item, walk_param_bound,
+    walk_poly_trait_ref, walk_trait_ref, walk_ty, walk_where_predicate, Visitor,
 };
 use rustc_hir::FnRetTy::Return;
 use rustc_hir::{
@@ -86,7 +86,35 @@
     "unused lifetimes in function definitions"
 }
 
-declare_lint_pass!(Lifetimes => [NEEDLESS_LIFETIMES, EXTRA_UNUSED_LIFETIMES]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for lifetimes in the generics of functions that are only used in outlives bounds
+    /// between lifetimes, like `'a: 'b`, and nowhere else in the function.
+    ///
+    /// ### Why is this bad?
+    /// The bounds don't constrain anything, so the lifetimes and their bounds can be removed.
+    /// `extra_unused_lifetimes` doesn't catch these, as it considers the bounds to be uses.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn unused_lifetimes<'a: 'b, 'b>(x: u8) {
+    ///     // ..
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// fn no_lifetimes(x: u8) {
+    ///     // ..
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub UNUSED_BOUNDED_LIFETIMES,
+    complexity,
+    "lifetimes in function definitions that are only used in outlives bounds"
+}
+
+declare_lint_pass!(Lifetimes => [NEEDLESS_LIFETIMES, EXTRA_UNUSED_LIFETIMES, UNUSED_BOUNDED_LIFETIMES]);
 
 impl<'tcx> LateLintPass<'tcx> for Lifetimes {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
@@ -134,7 +162,13 @@ fn check_fn_inner<'tcx>(
     span: Span,
     report_extra_lifetimes: bool,
 ) {
-    if in_external_macro(cx.sess(), span) || has_where_lifetimes(cx, generics) {
+    if in_external_macro(cx.sess(), span) {
+        return;
+    }
+    if report_extra_lifetimes {
+        report_unused_bounded_lifetimes(cx, sig.decl, body, generics);
+    }
+    if has_where_lifetimes(cx, generics) {
         return;
     }
 
@@ -685,6 +719,75 @@ fn report_extra_impl_lifetimes<'tcx>(cx: &LateContext<'tcx>, impl_: &'tcx Impl<'
     }
 }
 
+/// Reports the lifetimes only used in outlives bounds between lifetimes, as both `'a` and `'b` in
+/// `fn f<'a: 'b, 'b>()`.
+fn report_unused_bounded_lifetimes<'tcx>(
+    cx: &LateContext<'tcx>,
+    func: &'tcx FnDecl<'_>,
+    body: Option<BodyId>,
+    generics: &'tcx Generics<'_>,
+) {
+    let bounded = generics
+        .predicates
+        .iter()
+        .filter_map(|predicate| match predicate {
+            WherePredicate::RegionPredicate(region_predicate) => Some(region_predicate),
+            _ => None,
+        })
+        .flat_map(|region_predicate| {
+            std::iter::once(region_predicate.lifetime.ident.name).chain(region_predicate.bounds.iter().filter_map(
+                |bound| match bound {
+                    GenericBound::Outlives(lifetime) => Some(lifetime.ident.name),
+                    _ => None,
+                },
+            ))
+        })
+        .collect::<FxHashSet<_>>();
+    let hs = generics
+        .params
+        .iter()
+        .filter_map(|par| match par.kind {
+            GenericParamKind::Lifetime {
+                kind: LifetimeParamKind::Explicit,
+            } if !par.span.from_expansion() && bounded.contains(&par.name.ident().name) => {
+                Some((par.name.ident().name, par.span))
+            },
+            _ => None,
+        })
+        .collect::<FxHashMap<_, _>>();
+    if hs.is_empty() {
+        return;
+    }
+    // The body is visited as well, removing a lifetime used in the type of a local would break it
+    let mut checker = LifetimeChecker::<middle_nested_filter::OnlyBodies>::new(cx, hs);
+
+    for param in generics.params {
+        checker.visit_generic_param(param);
+    }
+    for predicate in generics.predicates {
+        if !matches!(predicate, WherePredicate::RegionPredicate(_)) {
+            walk_where_predicate(&mut checker, predicate);
+        }
+    }
+    walk_fn_decl(&mut checker, func);
+    if let Some(body_id) = body {
+        walk_body(&mut checker, cx.tcx.hir().body(body_id));
+    }
+
+    let mut spans = checker.map.into_values().collect::<Vec<_>>();
+    spans.sort();
+    for span in spans {
+        span_lint_and_help(
+            cx,
+            UNUSED_BOUNDED_LIFETIMES,
+            span,
+            "this lifetime is only used in outlives bounds",
+            None,
+            "consider removing the lifetime and the bounds mentioning it",
+        );
+    }
+}
+
 struct BodyLifetimeChecker {
     lifetimes_used_in_body: bool,
 }
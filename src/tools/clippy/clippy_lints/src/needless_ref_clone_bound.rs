@@ -0,0 +1,97 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{GenericBound, Generics, MutTy, Mutability, TraitBoundModifier, TyKind, WherePredicate};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `&T: Clone` bounds on references to a type parameter `T` that is itself
+    /// bounded by `Clone`.
+    ///
+    /// ### Why is this bad?
+    /// Shared references are `Clone` for any type, so the bound always holds. Such bounds are
+    /// usually written by mistake next to the `T: Clone` bound they were meant to be.
+    ///
+    /// ### Known problems
+    /// The lint only fires if `T: Clone` is also present, even though the bound is redundant
+    /// either way.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn f<'a, T: Clone>(x: &'a T)
+    /// where
+    ///     &'a T: Clone,
+    /// {
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f<'a, T: Clone>(x: &'a T) {}
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub NEEDLESS_REF_CLONE_BOUND,
+    pedantic,
+    "`&T: Clone` bounds next to a `T: Clone` bound"
+}
+declare_lint_pass!(NeedlessRefCloneBound => [NEEDLESS_REF_CLONE_BOUND]);
+
+fn is_clone_bound(bound: &GenericBound<'_>, clone_trait: DefId) -> bool {
+    matches!(bound, GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None)
+        if poly_trait_ref.trait_ref.trait_def_id() == Some(clone_trait))
+}
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessRefCloneBound {
+    fn check_generics(&mut self, cx: &LateContext<'tcx>, gen: &'tcx Generics<'tcx>) {
+        if gen.span.from_expansion() {
+            return;
+        }
+        let Some(clone_trait) = cx.tcx.lang_items().clone_trait() else {
+            return;
+        };
+
+        // The type parameters bounded by `Clone`, either inline or in the where clause
+        let clone_params = gen
+            .predicates
+            .iter()
+            .filter_map(|predicate| {
+                let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                    return None;
+                };
+                let (param_id, _) = bound_predicate.bounded_ty.as_generic_param()?;
+                bound_predicate
+                    .bounds
+                    .iter()
+                    .any(|bound| is_clone_bound(bound, clone_trait))
+                    .then_some(param_id)
+            })
+            .collect::<Vec<_>>();
+        if clone_params.is_empty() {
+            return;
+        }
+
+        for (pos, predicate) in gen.predicates.iter().enumerate() {
+            if let WherePredicate::BoundPredicate(bound_predicate) = predicate
+                && !bound_predicate.span.from_expansion()
+                && let TyKind::Ref(_, MutTy { ty, mutbl: Mutability::Not }) = bound_predicate.bounded_ty.kind
+                && let Some((param_id, _)) = ty.as_generic_param()
+                && clone_params.contains(&param_id)
+                && let Some(bound_pos) = bound_predicate
+                    .bounds
+                    .iter()
+                    .position(|bound| is_clone_bound(bound, clone_trait))
+            {
+                span_lint_and_sugg(
+                    cx,
+                    NEEDLESS_REF_CLONE_BOUND,
+                    gen.span_for_bound_removal(pos, bound_pos),
+                    "this bound always holds, as references are `Clone` for any type",
+                    "remove the bound",
+                    String::new(),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+    }
+}
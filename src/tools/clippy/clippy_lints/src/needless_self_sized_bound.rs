@@ -0,0 +1,114 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_hir::def::Res;
+use rustc_hir::{
+    GenericBound, ImplItem, ImplItemKind, Item, ItemKind, Node, Path, QPath, TraitBoundModifier, TyKind, WherePredicate,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `where Self: Sized` bounds on methods of inherent impls whose
+    /// self type is always sized.
+    ///
+    /// ### Why is this bad?
+    /// Outside of a trait, `Self` is a concrete type. If that type is known to
+    /// be `Sized`, the bound always holds and only adds noise.
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct Foo<T>(T);
+    ///
+    /// impl<T> Foo<T> {
+    ///     fn into_inner(self) -> T
+    ///     where
+    ///         Self: Sized,
+    ///     {
+    ///         self.0
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Foo<T>(T);
+    ///
+    /// impl<T> Foo<T> {
+    ///     fn into_inner(self) -> T {
+    ///         self.0
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub NEEDLESS_SELF_SIZED_BOUND,
+    complexity,
+    "`Self: Sized` bound on a method of an inherent impl whose self type is always sized"
+}
+declare_lint_pass!(NeedlessSelfSizedBound => [NEEDLESS_SELF_SIZED_BOUND]);
+
+impl<'tcx> LateLintPass<'tcx> for NeedlessSelfSizedBound {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        if !matches!(item.kind, ImplItemKind::Fn(..)) || item.span.from_expansion() {
+            return;
+        }
+        let parent = cx.tcx.hir().get_parent_item(item.hir_id());
+        let Some(Node::Item(Item {
+            kind: ItemKind::Impl(impl_),
+            owner_id,
+            ..
+        })) = cx.tcx.hir().find_by_def_id(parent.def_id) else {
+            return;
+        };
+        if impl_.of_trait.is_some() {
+            return;
+        }
+        let Some(sized_trait) = cx.tcx.lang_items().sized_trait() else {
+            return;
+        };
+
+        // Use the impl's environment rather than the method's, as the method's own `Self: Sized`
+        // bound would otherwise make this trivially true.
+        let self_ty = cx.tcx.type_of(*owner_id).subst_identity();
+        if !self_ty.is_sized(cx.tcx, cx.tcx.param_env(*owner_id)) {
+            return;
+        }
+
+        for predicate in item.generics.predicates {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            if bound_predicate.span.from_expansion()
+                || !matches!(
+                    bound_predicate.bounded_ty.kind,
+                    TyKind::Path(QPath::Resolved(
+                        None,
+                        Path {
+                            res: Res::SelfTyAlias { .. },
+                            ..
+                        }
+                    ))
+                )
+            {
+                continue;
+            }
+            for bound in bound_predicate.bounds {
+                if let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound
+                    && poly_trait_ref.trait_ref.trait_def_id() == Some(sized_trait)
+                {
+                    let (span, help) = if bound_predicate.bounds.len() == 1 {
+                        (bound_predicate.span, "remove the `Self: Sized` bound")
+                    } else {
+                        (bound.span(), "remove the `Sized` bound")
+                    };
+                    span_lint_and_help(
+                        cx,
+                        NEEDLESS_SELF_SIZED_BOUND,
+                        span,
+                        "`Self` is always `Sized` in this impl",
+                        None,
+                        help,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_opt;
+use rustc_ast::ast::PolyTraitRef;
+use rustc_errors::Applicability;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for trait bounds wrapped in parentheses, like `T: (Clone)`.
+    ///
+    /// The parentheses of the arguments of `Fn` traits, as in `T: Fn(u8) -> u8`, are part of the
+    /// bound and are not linted, and neither are parentheses around a bound with a `for<..>`
+    /// binder.
+    ///
+    /// ### Why is this bad?
+    /// The parentheses do not group anything, and make the bound harder to read.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn f<T: (Clone)>(x: T) {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn f<T: Clone>(x: T) {}
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub REDUNDANT_BOUND_PARENS,
+    style,
+    "trait bounds wrapped in parentheses"
+}
+declare_lint_pass!(RedundantBoundParens => [REDUNDANT_BOUND_PARENS]);
+
+impl EarlyLintPass for RedundantBoundParens {
+    fn check_poly_trait_ref(&mut self, cx: &EarlyContext<'_>, poly: &PolyTraitRef) {
+        if poly.span.from_expansion() || !poly.bound_generic_params.is_empty() {
+            return;
+        }
+        // The parentheses are only kept in the span of the bound, which otherwise starts with the
+        // modifiers or the path of the trait
+        let Some(snippet) = snippet_opt(cx, poly.span) else {
+            return;
+        };
+        if let Some(inner) = snippet.strip_prefix('(').and_then(|rest| rest.strip_suffix(')')) {
+            span_lint_and_sugg(
+                cx,
+                REDUNDANT_BOUND_PARENS,
+                poly.span,
+                "redundant parentheses around trait bound",
+                "remove them",
+                inner.trim().to_owned(),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
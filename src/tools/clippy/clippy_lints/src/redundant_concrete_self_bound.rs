@@ -0,0 +1,110 @@
+use clippy_utils::diagnostics::span_lint_and_remove_bounds;
+use clippy_utils::has_generic_args;
+use clippy_utils::ty::implements_trait_with_env;
+use rustc_hir::{GenericBound, ImplItem, ImplItemKind, PredicateOrigin, TraitBoundModifier, WherePredicate};
+use rustc_hir_analysis::hir_ty_to_ty;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{ParamEnv, TypeVisitableExt};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for bounds in the where clause of a method on the `Self` type of the impl, when
+    /// that type is concrete and implements the trait unconditionally.
+    ///
+    /// ### Why is this bad?
+    /// The bound doesn't depend on any generic parameter, so it always holds and only adds
+    /// noise to the signature.
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct Meters(u32);
+    ///
+    /// impl Meters {
+    ///     fn copied<T>(&self, _: T) -> Self
+    ///     where
+    ///         Meters: Clone,
+    ///     {
+    ///         self.clone()
+    ///     }
+    /// }
+    /// # impl Clone for Meters { fn clone(&self) -> Self { Meters(self.0) } }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Meters(u32);
+    ///
+    /// impl Meters {
+    ///     fn copied<T>(&self, _: T) -> Self {
+    ///         self.clone()
+    ///     }
+    /// }
+    /// # impl Clone for Meters { fn clone(&self) -> Self { Meters(self.0) } }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub REDUNDANT_CONCRETE_SELF_BOUND,
+    complexity,
+    "bounds in the where clause of a method on a concrete `Self` type that always hold"
+}
+declare_lint_pass!(RedundantConcreteSelfBound => [REDUNDANT_CONCRETE_SELF_BOUND]);
+
+impl<'tcx> LateLintPass<'tcx> for RedundantConcreteSelfBound {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        if !matches!(item.kind, ImplItemKind::Fn(..)) || item.span.from_expansion() {
+            return;
+        }
+        let parent = cx.tcx.hir().get_parent_item(item.hir_id());
+        let self_ty = cx.tcx.type_of(parent.to_def_id()).subst_identity();
+        // The bound could only hold for some of the generic arguments of the impl
+        if self_ty.has_non_region_param() {
+            return;
+        }
+
+        for (pred_pos, predicate) in item.generics.predicates.iter().enumerate() {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            if bound_predicate.origin != PredicateOrigin::WhereClause
+                || bound_predicate.span.from_expansion()
+                || !bound_predicate.bound_generic_params.is_empty()
+                || hir_ty_to_ty(cx.tcx, bound_predicate.bounded_ty) != self_ty
+            {
+                continue;
+            }
+
+            let redundant = bound_predicate
+                .bounds
+                .iter()
+                .enumerate()
+                .filter(|(_, bound)| {
+                    let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound else {
+                        return false;
+                    };
+                    // Only plain traits, their generic arguments could mention the parameters of the method
+                    let has_args = has_generic_args(poly_trait_ref.trait_ref.path);
+                    let Some(trait_id) = poly_trait_ref.trait_ref.trait_def_id() else {
+                        return false;
+                    };
+                    // `Self: Sized` is linted by `needless_self_sized_bound`. Traits with defaulted parameters, like
+                    // `PartialEq`, would need their defaults as arguments. The bound is checked without the where
+                    // clause of the method, which contains the bound itself
+                    !has_args
+                        && poly_trait_ref.bound_generic_params.is_empty()
+                        && Some(trait_id) != cx.tcx.lang_items().sized_trait()
+                        && cx.tcx.generics_of(trait_id).count() == 1
+                        && implements_trait_with_env(cx.tcx, ParamEnv::empty(), self_ty, trait_id, [])
+                })
+                .map(|(bound_pos, _)| bound_pos)
+                .collect::<Vec<_>>();
+
+            span_lint_and_remove_bounds(
+                cx,
+                REDUNDANT_CONCRETE_SELF_BOUND,
+                item.generics,
+                pred_pos,
+                &redundant,
+                &format!("this bound is always satisfied by `{self_ty}`"),
+            );
+        }
+    }
+}
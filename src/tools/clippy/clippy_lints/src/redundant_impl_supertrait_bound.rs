@@ -0,0 +1,124 @@
+use clippy_utils::diagnostics::span_lint_and_remove_bounds;
+use clippy_utils::has_generic_args;
+use clippy_utils::ty::{implements_trait_with_env, param_env_without};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{GenericBound, Impl, Item, ItemKind, PredicateOrigin, TraitBoundModifier, WherePredicate};
+use rustc_hir_analysis::hir_ty_to_ty;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::Ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_trait_selection::traits::supertrait_def_ids;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for bounds in the where clause of a trait impl which require the implementing type
+    /// to implement a supertrait of the implemented trait, when that supertrait is implemented
+    /// for the type without the bound anyway.
+    ///
+    /// ### Why is this bad?
+    /// Implementing the trait already requires implementing its supertraits, so the bound only
+    /// restates the supertrait.
+    ///
+    /// ### Example
+    /// ```rust
+    /// trait Super {}
+    /// trait Sub: Super {}
+    /// struct Wrap<T>(T);
+    ///
+    /// impl<T> Super for Wrap<T> {}
+    /// impl<T> Sub for Wrap<T> where Wrap<T>: Super {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// trait Super {}
+    /// trait Sub: Super {}
+    /// struct Wrap<T>(T);
+    ///
+    /// impl<T> Super for Wrap<T> {}
+    /// impl<T> Sub for Wrap<T> {}
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub REDUNDANT_IMPL_SUPERTRAIT_BOUND,
+    complexity,
+    "bounds in the where clause of a trait impl that restate a supertrait of the implemented trait"
+}
+declare_lint_pass!(RedundantImplSupertraitBound => [REDUNDANT_IMPL_SUPERTRAIT_BOUND]);
+
+/// Whether `self_ty` implements the supertrait without the `self_ty: super_id` predicates of the
+/// impl. The bound can be needed when the supertrait is only implemented conditionally, as in
+/// `impl<T: Clone> Super for Wrap<T>`.
+fn holds_without_bound<'tcx>(cx: &LateContext<'tcx>, impl_id: DefId, self_ty: Ty<'tcx>, super_id: DefId) -> bool {
+    let param_env = param_env_without(cx.tcx, cx.tcx.param_env(impl_id), |trait_predicate| {
+        trait_predicate.def_id() == super_id && trait_predicate.self_ty() == self_ty
+    });
+    implements_trait_with_env(cx.tcx, param_env, self_ty, super_id, [])
+}
+
+impl<'tcx> LateLintPass<'tcx> for RedundantImplSupertraitBound {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        let ItemKind::Impl(Impl {
+            generics,
+            of_trait: Some(trait_ref),
+            ..
+        }) = item.kind
+        else {
+            return;
+        };
+        let Some(trait_id) = trait_ref.trait_def_id() else {
+            return;
+        };
+        if item.span.from_expansion() {
+            return;
+        }
+        let impl_id = item.owner_id.to_def_id();
+        let self_ty = cx.tcx.type_of(impl_id).subst_identity();
+
+        for (pred_pos, predicate) in generics.predicates.iter().enumerate() {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            if bound_predicate.origin != PredicateOrigin::WhereClause
+                || bound_predicate.span.from_expansion()
+                || !bound_predicate.bound_generic_params.is_empty()
+                || hir_ty_to_ty(cx.tcx, bound_predicate.bounded_ty) != self_ty
+            {
+                continue;
+            }
+
+            let redundant = bound_predicate
+                .bounds
+                .iter()
+                .enumerate()
+                .filter(|(_, bound)| {
+                    let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound else {
+                        return false;
+                    };
+                    // Only plain supertraits without defaulted parameters, like `PartialEq`, their generic arguments
+                    // would have to be compared as well
+                    let has_args = has_generic_args(poly_trait_ref.trait_ref.path);
+                    let Some(super_id) = poly_trait_ref.trait_ref.trait_def_id() else {
+                        return false;
+                    };
+                    !has_args
+                        && cx.tcx.generics_of(super_id).count() == 1
+                        && super_id != trait_id
+                        && supertrait_def_ids(cx.tcx, trait_id).any(|id| id == super_id)
+                        && holds_without_bound(cx, impl_id, self_ty, super_id)
+                })
+                .map(|(bound_pos, _)| bound_pos)
+                .collect::<Vec<_>>();
+
+            span_lint_and_remove_bounds(
+                cx,
+                REDUNDANT_IMPL_SUPERTRAIT_BOUND,
+                generics,
+                pred_pos,
+                &redundant,
+                &format!(
+                    "this bound is already required by the supertraits of `{}`",
+                    cx.tcx.item_name(trait_id)
+                ),
+            );
+        }
+    }
+}
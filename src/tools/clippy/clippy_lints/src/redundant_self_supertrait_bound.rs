@@ -0,0 +1,144 @@
+use crate::trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS;
+use clippy_utils::diagnostics::span_lint_and_remove_bounds;
+use clippy_utils::{has_generic_args, is_lint_allowed};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::Res;
+use rustc_hir::{
+    self as hir, GenericBound, PredicateOrigin, QPath, TraitBoundModifier, TraitItem, TraitItemKind, TyKind,
+    WherePredicate,
+};
+use rustc_hir_analysis::hir_trait_to_predicates;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Clause, PredicateKind, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_trait_selection::traits::supertraits;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Self` bounds in the where clause of a trait method which require a supertrait
+    /// of the trait, either declared on the trait or required through another supertrait. The
+    /// declared supertraits are left to `trait_duplication_in_bounds` if it is enabled.
+    ///
+    /// ### Why is this bad?
+    /// `Self` always implements all the supertraits of the trait, so the bound only restates
+    /// them.
+    ///
+    /// ### Example
+    /// ```rust
+    /// trait Named: Clone {}
+    /// trait Tagged: Named {
+    ///     fn tag(&self) -> Self
+    ///     where
+    ///         Self: Clone;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// trait Named: Clone {}
+    /// trait Tagged: Named {
+    ///     fn tag(&self) -> Self;
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub REDUNDANT_SELF_SUPERTRAIT_BOUND,
+    complexity,
+    "`Self` bounds in the where clause of a trait method that restate a supertrait of the trait"
+}
+declare_lint_pass!(RedundantSelfSupertraitBound => [REDUNDANT_SELF_SUPERTRAIT_BOUND]);
+
+/// Lowers the trait ref of a bound on `self_ty`, with the defaulted generic parameters of the trait
+/// filled in.
+fn lowered_trait_ref<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_ref: &hir::TraitRef<'_>,
+    self_ty: Ty<'tcx>,
+) -> Option<ty::TraitRef<'tcx>> {
+    hir_trait_to_predicates(cx.tcx, trait_ref, self_ty)
+        .predicates()
+        .find_map(|(predicate, _)| match predicate.kind().no_bound_vars()? {
+            PredicateKind::Clause(Clause::Trait(trait_predicate)) => Some(trait_predicate.trait_ref),
+            _ => None,
+        })
+}
+
+impl<'tcx> LateLintPass<'tcx> for RedundantSelfSupertraitBound {
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'tcx>) {
+        if !matches!(item.kind, TraitItemKind::Fn(..)) || item.span.from_expansion() {
+            return;
+        }
+
+        for (pred_pos, predicate) in item.generics.predicates.iter().enumerate() {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            if bound_predicate.origin != PredicateOrigin::WhereClause
+                || bound_predicate.span.from_expansion()
+                || !bound_predicate.bound_generic_params.is_empty()
+            {
+                continue;
+            }
+            let TyKind::Path(QPath::Resolved(None, path)) = bound_predicate.bounded_ty.kind else {
+                continue;
+            };
+            let Res::SelfTyParam { trait_: trait_id } = path.res else {
+                continue;
+            };
+
+            // The supertraits declared on the trait itself are linted by `trait_duplication_in_bounds`
+            // if it's enabled, so that only one of the lints fires
+            let declared = if is_lint_allowed(cx, TRAIT_DUPLICATION_IN_BOUNDS, item.hir_id()) {
+                FxHashSet::default()
+            } else {
+                cx.tcx
+                    .super_predicates_of(trait_id)
+                    .predicates
+                    .iter()
+                    .filter_map(|(predicate, _)| match predicate.kind().no_bound_vars()? {
+                        PredicateKind::Clause(Clause::Trait(trait_predicate)) => Some(trait_predicate.trait_ref),
+                        _ => None,
+                    })
+                    .collect::<FxHashSet<_>>()
+            };
+
+            // The supertraits with their generic arguments, as in `PartialEq<u8>`
+            let self_trait_ref = ty::TraitRef::identity(cx.tcx, trait_id);
+            let supertraits = supertraits(cx.tcx, ty::Binder::dummy(self_trait_ref))
+                .filter_map(|trait_ref| trait_ref.no_bound_vars())
+                .filter(|&trait_ref| trait_ref != self_trait_ref)
+                .collect::<FxHashSet<_>>();
+
+            let redundant = bound_predicate
+                .bounds
+                .iter()
+                .enumerate()
+                .filter(|(_, bound)| {
+                    let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound else {
+                        return false;
+                    };
+                    // Only plain supertraits, the bindings of their associated types would have to be compared as well
+                    if has_generic_args(poly_trait_ref.trait_ref.path) {
+                        return false;
+                    }
+                    // The bound with its defaulted parameters, `Self: PartialEq` is `Self: PartialEq<Self>`
+                    lowered_trait_ref(cx, &poly_trait_ref.trait_ref, self_trait_ref.self_ty())
+                        .map_or(false, |trait_ref| {
+                            supertraits.contains(&trait_ref) && !declared.contains(&trait_ref)
+                        })
+                })
+                .map(|(bound_pos, _)| bound_pos)
+                .collect::<Vec<_>>();
+
+            span_lint_and_remove_bounds(
+                cx,
+                REDUNDANT_SELF_SUPERTRAIT_BOUND,
+                item.generics,
+                pred_pos,
+                &redundant,
+                &format!(
+                    "this bound is already required by the supertraits of `{}`",
+                    cx.tcx.item_name(trait_id)
+                ),
+            );
+        }
+    }
+}
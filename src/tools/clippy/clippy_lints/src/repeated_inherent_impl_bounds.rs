@@ -0,0 +1,115 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::is_lint_allowed;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{Item, ItemKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{Predicate, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for inherent impl blocks of the same type that repeat the same bounds, like two
+    /// `impl<T: Clone> Foo<T>` blocks.
+    ///
+    /// ### Why is this bad?
+    /// The bounds have to be kept in sync between the blocks, which could be merged into one.
+    /// Blocks are sometimes split on purpose though, e.g. to group related methods, so this is a
+    /// restriction lint.
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct Foo<T>(T);
+    ///
+    /// impl<T: Clone> Foo<T> {
+    ///     fn one(&self) {}
+    /// }
+    ///
+    /// impl<T: Clone> Foo<T> {
+    ///     fn other(&self) {}
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Foo<T>(T);
+    ///
+    /// impl<T: Clone> Foo<T> {
+    ///     fn one(&self) {}
+    ///     fn other(&self) {}
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub REPEATED_INHERENT_IMPL_BOUNDS,
+    restriction,
+    "inherent impl blocks of the same type that repeat the same bounds"
+}
+declare_lint_pass!(RepeatedInherentImplBounds => [REPEATED_INHERENT_IMPL_BOUNDS]);
+
+impl<'tcx> LateLintPass<'tcx> for RepeatedInherentImplBounds {
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        // List of spans to lint. (lint_span, first_span, self_ty)
+        let mut lint_spans = Vec::new();
+
+        let inherent_impls = cx
+            .tcx
+            .with_stable_hashing_context(|hcx| cx.tcx.crate_inherent_impls(()).inherent_impls.to_sorted(&hcx, true));
+
+        for (_, impl_ids) in inherent_impls.into_iter().filter(|(_, impls)| impls.len() > 1) {
+            // The first block of each self type and set of bounds. The generic parameters of the
+            // blocks are compared by index and name, so `T` is the same parameter in every block.
+            let mut firsts: Vec<(Ty<'tcx>, FxHashSet<Predicate<'tcx>>, Span)> = Vec::new();
+            for impl_id in impl_ids.iter().filter_map(|id| id.as_local()) {
+                let Some(span) = get_impl_header_span(cx, impl_id) else {
+                    continue;
+                };
+                let self_ty = cx.tcx.type_of(impl_id).subst_identity();
+                let bounds = cx
+                    .tcx
+                    .predicates_of(impl_id)
+                    .predicates
+                    .iter()
+                    .map(|&(predicate, _)| predicate)
+                    .collect::<FxHashSet<_>>();
+                match firsts.iter().find(|(ty, preds, _)| *ty == self_ty && *preds == bounds) {
+                    Some(&(_, _, first_span)) => lint_spans.push((span, first_span, self_ty)),
+                    None => firsts.push((self_ty, bounds, span)),
+                }
+            }
+        }
+
+        // `TyCtxt::crate_inherent_impls` doesn't have a defined order. Sort the lint output first.
+        lint_spans.sort_by_key(|x| x.0.lo());
+        for (span, first_span, self_ty) in lint_spans {
+            span_lint_and_then(
+                cx,
+                REPEATED_INHERENT_IMPL_BOUNDS,
+                span,
+                &format!("this impl block repeats the bounds of another impl block of `{self_ty}`"),
+                |diag| {
+                    diag.span_note(first_span, "the other impl block is here");
+                    diag.help("consider merging the impl blocks");
+                },
+            );
+        }
+    }
+}
+
+/// Gets the span of the header of the given impl block, up to its self type, unless the block
+/// has no bounds written on it or isn't being considered by the lint.
+fn get_impl_header_span(cx: &LateContext<'_>, id: LocalDefId) -> Option<Span> {
+    let id = cx.tcx.hir().local_def_id_to_hir_id(id);
+    if let Node::Item(&Item {
+        kind: ItemKind::Impl(impl_item),
+        span,
+        ..
+    }) = cx.tcx.hir().get(id)
+    {
+        (!span.from_expansion()
+            && !impl_item.generics.predicates.is_empty()
+            && !is_lint_allowed(cx, REPEATED_INHERENT_IMPL_BOUNDS, id))
+        .then(|| span.with_hi(impl_item.self_ty.span.hi()))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,362 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::trait_ref_of_method;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::{walk_impl_item, walk_item, walk_param_bound, walk_ty, walk_where_predicate, Visitor};
+use rustc_hir::{
+    Expr, ExprKind, FnDecl, GenericBound, GenericParam, GenericParamKind, Generics, ImplItem, ImplItemKind, Item,
+    ItemKind, PredicateOrigin, QPath, TraitBoundModifier, Ty, TyKind, WherePredicate,
+};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::def_id::{DefId, LocalDefId};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for type parameters of functions that are only used as the type of a single
+    /// argument, and could therefore be written as `impl Trait` in argument position.
+    ///
+    /// ### Why is this bad?
+    /// Naming the type parameter adds noise to the signature without making it any more
+    /// expressive, as the parameter is never referred to again.
+    ///
+    /// ### Known problems
+    /// Callers can no longer specify the type with a turbofish. Functions called with explicit
+    /// generic arguments anywhere in the crate are not linted, but call sites in other crates will
+    /// fail to compile after applying the suggestion.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn print<T: std::fmt::Display>(x: T) {
+    ///     println!("{x}");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn print(x: impl std::fmt::Display) {
+    ///     println!("{x}");
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub SINGLE_USE_TYPE_PARAM,
+    pedantic,
+    "type parameters only used for the type of a single argument, which could use `impl Trait` instead"
+}
+
+pub struct SingleUseTypeParam {
+    avoid_breaking_exported_api: bool,
+    /// The lints on the functions checked so far. They are emitted at the end of the crate, once
+    /// all the call sites have been seen.
+    potential_lints: Vec<PotentialLint>,
+    /// Functions named with explicit generic arguments, as in `f::<u32>(x)`.
+    explicitly_instantiated: FxHashSet<DefId>,
+}
+
+impl SingleUseTypeParam {
+    pub fn new(avoid_breaking_exported_api: bool) -> Self {
+        Self {
+            avoid_breaking_exported_api,
+            potential_lints: Vec::new(),
+            explicitly_instantiated: FxHashSet::default(),
+        }
+    }
+
+    /// Don't lint in external macros. Also, don't lint exported items if the
+    /// `avoid_breaking_exported_api` config option is set, as this would break turbofish call sites
+    /// in other crates.
+    fn is_exported_or_macro(&self, cx: &LateContext<'_>, span: Span, def_id: LocalDefId) -> bool {
+        let is_exported = cx.effective_visibilities.is_exported(def_id);
+        in_external_macro(cx.sess(), span) || span.from_expansion() || (is_exported && self.avoid_breaking_exported_api)
+    }
+}
+
+impl_lint_pass!(SingleUseTypeParam => [SINGLE_USE_TYPE_PARAM]);
+
+const HELP: &str = "consider using `impl Trait` in argument position instead";
+
+/// A lint on the type parameters of a function, which is dropped if the function turns out to be
+/// called with explicit generic arguments.
+struct PotentialLint {
+    fn_id: LocalDefId,
+    param_spans: Vec<Span>,
+    msg: String,
+    /// The suggestion and its applicability, if one could be formed.
+    suggestion: Option<(Vec<(Span, String)>, Applicability)>,
+}
+
+/// A visitor struct that walks a given function and counts how many times each of its type
+/// parameters is named, not counting the bounded type of the parameter's own bounds.
+struct TypeParamUses<'cx, 'tcx> {
+    cx: &'cx LateContext<'tcx>,
+    uses: FxHashMap<DefId, usize>,
+    /// The bounds of each type parameter, in source order.
+    bounds: FxHashMap<DefId, Vec<&'tcx GenericBound<'tcx>>>,
+    /// Span of the inline bounds of each type parameter.
+    inline_bounds: FxHashMap<DefId, Span>,
+    /// Type parameters with bounds that appear in a where clause.
+    where_bounds: FxHashSet<DefId>,
+}
+
+impl<'cx, 'tcx> TypeParamUses<'cx, 'tcx> {
+    fn new(cx: &'cx LateContext<'tcx>, generics: &'tcx Generics<'tcx>) -> Self {
+        let uses = generics
+            .params
+            .iter()
+            .filter_map(|param| match param.kind {
+                GenericParamKind::Type {
+                    synthetic: false,
+                    default: None,
+                } if !param.span.from_expansion() => Some((param.def_id.to_def_id(), 0)),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            cx,
+            uses,
+            bounds: FxHashMap::default(),
+            inline_bounds: FxHashMap::default(),
+            where_bounds: FxHashSet::default(),
+        }
+    }
+}
+
+impl<'cx, 'tcx> Visitor<'tcx> for TypeParamUses<'cx, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn visit_ty(&mut self, t: &'tcx Ty<'tcx>) {
+        if let Some((def_id, _)) = t.as_generic_param() {
+            if let Some(uses) = self.uses.get_mut(&def_id) {
+                *uses += 1;
+            }
+        } else if let TyKind::OpaqueDef(id, _, _) = t.kind {
+            // `walk_ty` would call `visit_nested_item` here, which doesn't walk the item with the
+            // `OnlyBodies` filter, see `extra_unused_type_parameters`.
+            let item = self.nested_visit_map().item(id);
+            walk_item(self, item);
+        } else {
+            walk_ty(self, t);
+        }
+    }
+
+    fn visit_where_predicate(&mut self, predicate: &'tcx WherePredicate<'tcx>) {
+        if let WherePredicate::BoundPredicate(predicate) = predicate
+            && predicate.origin != PredicateOrigin::ImplTrait
+            && predicate.bound_generic_params.is_empty()
+            && let Some((def_id, _)) = predicate.bounded_ty.as_generic_param()
+            && self.uses.contains_key(&def_id)
+        {
+            if predicate.origin == PredicateOrigin::WhereClause {
+                self.where_bounds.insert(def_id);
+            } else {
+                self.inline_bounds.insert(def_id, predicate.span);
+            }
+            self.bounds.entry(def_id).or_default().extend(predicate.bounds);
+
+            // Only walk the right-hand side, so that `T: PartialEq<T>` still counts as a use
+            for bound in predicate.bounds {
+                walk_param_bound(self, bound);
+            }
+        } else {
+            walk_where_predicate(self, predicate);
+        }
+    }
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+}
+
+fn check_fn<'tcx>(
+    cx: &LateContext<'tcx>,
+    fn_id: LocalDefId,
+    generics: &'tcx Generics<'tcx>,
+    decl: &'tcx FnDecl<'tcx>,
+    uses: &TypeParamUses<'_, 'tcx>,
+) -> Option<PotentialLint> {
+    let explicit_params = generics
+        .params
+        .iter()
+        .filter(|param| !param.is_elided_lifetime() && !param.is_impl_trait())
+        .collect::<Vec<_>>();
+
+    // The type parameters named exactly once, as the type of an argument, along with that argument
+    let single_use_params = explicit_params
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, param)| {
+            let def_id = param.def_id.to_def_id();
+            // `impl Trait` needs at least one trait bound
+            if uses.uses.get(&def_id) != Some(&1)
+                || !uses
+                    .bounds
+                    .get(&def_id)?
+                    .iter()
+                    .any(|bound| matches!(bound, GenericBound::Trait(_, TraitBoundModifier::None)))
+            {
+                return None;
+            }
+            let arg = decl
+                .inputs
+                .iter()
+                .find(|ty| ty.peel_refs().as_generic_param().map(|(id, _)| id) == Some(def_id))?;
+            Some((idx, *param, arg.peel_refs(), arg.span != arg.peel_refs().span))
+        })
+        .collect::<Vec<_>>();
+
+    let msg = match single_use_params.as_slice() {
+        [] => return None,
+        [(_, param, ..)] => format!(
+            "type parameter `{}` is only used for the type of a single argument",
+            param.name.ident()
+        ),
+        _ => format!(
+            "type parameters are only used for the type of a single argument each: {}",
+            single_use_params
+                .iter()
+                .map(|(_, param, ..)| param.name.ident().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+
+    let param_spans = single_use_params
+        .iter()
+        .map(|(_, param, ..)| param.span)
+        .collect::<Vec<_>>();
+
+    // If any parameters are bounded in where clauses, don't try to form a suggestion, removing the
+    // where clause predicates could leave it empty.
+    if single_use_params
+        .iter()
+        .any(|(_, param, ..)| uses.where_bounds.contains(&param.def_id.to_def_id()))
+    {
+        return Some(PotentialLint {
+            fn_id,
+            param_spans,
+            msg,
+            suggestion: None,
+        });
+    }
+
+    let bound_span = |param: &GenericParam<'_>| {
+        uses.inline_bounds
+            .get(&param.def_id.to_def_id())
+            .map_or(param.span, |bound_span| param.span.with_hi(bound_span.hi()))
+    };
+
+    let mut app = Applicability::MachineApplicable;
+    let mut suggestions = Vec::new();
+
+    // Remove the parameters from the list of generics, in the same way as
+    // `extra_unused_type_parameters`
+    if explicit_params.len() == single_use_params.len() {
+        suggestions.push((generics.span, String::new()));
+    } else {
+        let mut end: Option<LocalDefId> = None;
+        for (idx, param, ..) in single_use_params.iter().rev() {
+            let span = if let Some(next) = explicit_params.get(idx + 1) && end != Some(next.def_id) {
+                bound_span(param).until(next.span)
+            } else {
+                end = Some(param.def_id);
+                let prev_span = bound_span(explicit_params[idx - 1]);
+                bound_span(param).with_lo(prev_span.hi())
+            };
+            suggestions.push((span, String::new()));
+        }
+    }
+
+    // Replace the type of the argument with `impl Trait`
+    for (_, param, arg, behind_ref) in &single_use_params {
+        let bounds = &uses.bounds[&param.def_id.to_def_id()];
+        let impl_trait = format!(
+            "impl {}",
+            bounds
+                .iter()
+                .map(|bound| snippet_with_applicability(cx, bound.span(), "..", &mut app))
+                .collect::<Vec<_>>()
+                .join(" + ")
+        );
+        // `&impl A + B` is ambiguous
+        let impl_trait = if *behind_ref && bounds.len() > 1 {
+            format!("({impl_trait})")
+        } else {
+            impl_trait
+        };
+        suggestions.push((arg.span, impl_trait));
+    }
+
+    Some(PotentialLint {
+        fn_id,
+        param_spans,
+        msg,
+        suggestion: Some((suggestions, app)),
+    })
+}
+
+impl<'tcx> LateLintPass<'tcx> for SingleUseTypeParam {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Fn(sig, generics, _) = item.kind
+            && !self.is_exported_or_macro(cx, item.span, item.owner_id.def_id)
+        {
+            let mut uses = TypeParamUses::new(cx, generics);
+            walk_item(&mut uses, item);
+            self.potential_lints
+                .extend(check_fn(cx, item.owner_id.def_id, generics, sig.decl, &uses));
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        // Only lint on inherent methods, not trait methods.
+        if let ImplItemKind::Fn(sig, _) = item.kind
+            && trait_ref_of_method(cx, item.owner_id.def_id).is_none()
+            && !self.is_exported_or_macro(cx, item.span, item.owner_id.def_id)
+        {
+            let mut uses = TypeParamUses::new(cx, item.generics);
+            walk_impl_item(&mut uses, item);
+            self.potential_lints
+                .extend(check_fn(cx, item.owner_id.def_id, item.generics, sig.decl, &uses));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        // The generic arguments of the function itself, not the ones of the type in `Type::<T>::f`
+        let (def_id, args) = match expr.kind {
+            ExprKind::Path(ref qpath) => {
+                let args = match qpath {
+                    QPath::Resolved(_, path) => path.segments.last().and_then(|segment| segment.args),
+                    QPath::TypeRelative(_, segment) => segment.args,
+                    QPath::LangItem(..) => None,
+                };
+                (cx.qpath_res(qpath, expr.hir_id).opt_def_id(), args)
+            },
+            ExprKind::MethodCall(segment, ..) => (cx.typeck_results().type_dependent_def_id(expr.hir_id), segment.args),
+            _ => return,
+        };
+        if let Some(def_id) = def_id
+            && args.map_or(false, |args| !args.args.is_empty())
+        {
+            self.explicitly_instantiated.insert(def_id);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for lint in self.potential_lints.drain(..) {
+            if self.explicitly_instantiated.contains(&lint.fn_id.to_def_id()) {
+                continue;
+            }
+            let hir_id = cx.tcx.hir().local_def_id_to_hir_id(lint.fn_id);
+            span_lint_hir_and_then(cx, SINGLE_USE_TYPE_PARAM, hir_id, lint.param_spans, &lint.msg, |diag| {
+                if let Some((suggestions, app)) = lint.suggestion {
+                    diag.multipart_suggestion(HELP, suggestions, app);
+                } else {
+                    diag.help(HELP);
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use clippy_utils::diagnostics::span_lint_and_remove_bounds;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::implements_trait_with_env;
+use clippy_utils::{has_generic_args, is_lint_allowed};
+use rustc_hir::{
+    GenericBound, Generics, ImplItemKind, Node, PredicateOrigin, TraitBoundModifier, TyKind, WherePredicate,
+};
+use rustc_hir_analysis::hir_ty_to_ty;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{ParamEnv, Ty, TypeVisitableExt};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+use crate::degenerate_where_bound::DEGENERATE_WHERE_BOUND;
+use crate::redundant_concrete_self_bound::REDUNDANT_CONCRETE_SELF_BOUND;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for bounds in where clauses on types that don't depend on any generic parameter
+    /// and implement the trait unconditionally, like `dyn Trait: Trait` or `i32: Copy`. The
+    /// bounds on the `Self` type of methods are checked by `redundant_concrete_self_bound`, and
+    /// the ones on `()` and `!` by `degenerate_where_bound`.
+    ///
+    /// ### Why is this bad?
+    /// The bound always holds, so it only adds noise to the signature.
+    ///
+    /// ### Example
+    /// ```rust
+    /// trait Shape {
+    ///     fn area(&self) -> f64;
+    /// }
+    ///
+    /// fn total_area<T: AsRef<[Box<dyn Shape>]>>(shapes: T) -> f64
+    /// where
+    ///     dyn Shape: Shape,
+    /// {
+    ///     shapes.as_ref().iter().map(|shape| shape.area()).sum()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// trait Shape {
+    ///     fn area(&self) -> f64;
+    /// }
+    ///
+    /// fn total_area<T: AsRef<[Box<dyn Shape>]>>(shapes: T) -> f64 {
+    ///     shapes.as_ref().iter().map(|shape| shape.area()).sum()
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub TAUTOLOGICAL_BOUNDS,
+    complexity,
+    "bounds in where clauses on types that always implement the trait"
+}
+declare_lint_pass!(TautologicalBounds => [TAUTOLOGICAL_BOUNDS]);
+
+impl<'tcx> LateLintPass<'tcx> for TautologicalBounds {
+    fn check_generics(&mut self, cx: &LateContext<'tcx>, gen: &'tcx Generics<'tcx>) {
+        if gen.span.from_expansion() {
+            return;
+        }
+        let self_ty = concrete_self_ty(cx);
+        let degenerate_allowed = is_lint_allowed(cx, DEGENERATE_WHERE_BOUND, cx.last_node_with_lint_attrs);
+
+        for (pred_pos, predicate) in gen.predicates.iter().enumerate() {
+            let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+                continue;
+            };
+            if bound_predicate.origin != PredicateOrigin::WhereClause
+                || bound_predicate.span.from_expansion()
+                || !bound_predicate.bound_generic_params.is_empty()
+                || (!degenerate_allowed && matches!(bound_predicate.bounded_ty.kind, TyKind::Tup([]) | TyKind::Never))
+            {
+                continue;
+            }
+            // The bound could only hold for some of the generic arguments
+            let ty = hir_ty_to_ty(cx.tcx, bound_predicate.bounded_ty);
+            if ty.has_non_region_param() || Some(ty) == self_ty {
+                continue;
+            }
+
+            let redundant = bound_predicate
+                .bounds
+                .iter()
+                .enumerate()
+                .filter(|(_, bound)| {
+                    let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound else {
+                        return false;
+                    };
+                    // Only plain traits, their generic arguments could mention generic parameters
+                    let has_args = has_generic_args(poly_trait_ref.trait_ref.path);
+                    let Some(trait_id) = poly_trait_ref.trait_ref.trait_def_id() else {
+                        return false;
+                    };
+                    // Traits with defaulted parameters, like `PartialEq`, would need their defaults as arguments. The
+                    // bound is checked without the where clause, which contains the bound itself
+                    !has_args
+                        && poly_trait_ref.bound_generic_params.is_empty()
+                        && cx.tcx.generics_of(trait_id).count() == 1
+                        && implements_trait_with_env(cx.tcx, ParamEnv::empty(), ty, trait_id, [])
+                })
+                .map(|(bound_pos, _)| bound_pos)
+                .collect::<Vec<_>>();
+
+            // Show the type as written, `dyn Trait` would be printed as `(dyn Trait + 'static)`
+            let ty_snippet = snippet(cx, bound_predicate.bounded_ty.span, "..");
+            span_lint_and_remove_bounds(
+                cx,
+                TAUTOLOGICAL_BOUNDS,
+                gen,
+                pred_pos,
+                &redundant,
+                &format!("this bound is always satisfied by `{ty_snippet}`"),
+            );
+        }
+    }
+}
+
+/// Gets the `Self` type of the impl if the generics being checked are the ones of a method with a
+/// concrete `Self` type, on which the bounds are left to `redundant_concrete_self_bound`.
+fn concrete_self_ty<'tcx>(cx: &LateContext<'tcx>) -> Option<Ty<'tcx>> {
+    let id = cx.last_node_with_lint_attrs;
+    if let Some(Node::ImplItem(item)) = cx.tcx.hir().find(id)
+        && matches!(item.kind, ImplItemKind::Fn(..))
+        && !is_lint_allowed(cx, REDUNDANT_CONCRETE_SELF_BOUND, id)
+    {
+        let parent = cx.tcx.hir().get_parent_item(id);
+        let self_ty = cx.tcx.type_of(parent.to_def_id()).subst_identity();
+        (!self_ty.has_non_region_param()).then_some(self_ty)
+    } else {
+        None
+    }
+}
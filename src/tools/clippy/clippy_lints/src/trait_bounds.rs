@@ -1,21 +1,27 @@
 use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
 use clippy_utils::source::{snippet, snippet_opt, snippet_with_applicability};
-use clippy_utils::{SpanlessEq, SpanlessHash};
+use clippy_utils::{remaining_bounds_sugg, SpanlessEq, SpanlessHash};
 use core::hash::{Hash, Hasher};
 use if_chain::if_chain;
 use itertools::Itertools;
-use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxHasher};
 use rustc_data_structures::unhash::UnhashMap;
 use rustc_errors::Applicability;
-use rustc_hir::def::Res;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_param_bound, walk_path, walk_ty, Visitor};
 use rustc_hir::{
-    GenericArg, GenericBound, Generics, Item, ItemKind, Node, Path, PathSegment, PredicateOrigin, QPath,
-    TraitBoundModifier, TraitItem, TraitRef, Ty, TyKind, WherePredicate,
+    FnDecl, FnRetTy, ForeignItem, ForeignItemKind, GenericArg, GenericBound, Generics, HirId, ImplItem, ImplItemKind,
+    Item, ItemKind, LifetimeName, Node, OpaqueTyOrigin, Path, PathSegment, PredicateOrigin, QPath, TraitBoundModifier,
+    TraitItem, TraitItemKind, TraitRef, Ty, TyKind, WherePredicate,
 };
+use rustc_hir_analysis::hir_ty_to_ty;
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, TypeVisitableExt};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::{BytePos, Span};
 use std::collections::hash_map::Entry;
+use std::mem;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -103,7 +109,7 @@ pub fn new(max_trait_bounds: u64) -> Self {
 impl<'tcx> LateLintPass<'tcx> for TraitBounds {
     fn check_generics(&mut self, cx: &LateContext<'tcx>, gen: &'tcx Generics<'_>) {
         self.check_type_repetition(cx, gen);
-        check_trait_bound_duplication(cx, gen);
+        check_trait_object_predicates(cx, gen);
     }
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
@@ -114,7 +120,36 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
             ..
         } = item
         {
-            rollup_traits(cx, bounds, "these bounds contain repeated elements");
+            rollup_traits(cx, bounds, "these bounds contain repeated elements", None);
+        }
+
+        if let ItemKind::Impl(impl_) = item.kind
+            && let Some(trait_ref) = cx.tcx.impl_trait_ref(item.owner_id)
+        {
+            check_assoc_type_bound_duplication(cx, impl_.generics, trait_ref.subst_identity());
+        }
+
+        // The generics are checked along with the item, so that the signature of functions is at hand
+        if let Some(gen) = item.kind.generics() {
+            let decl = match item.kind {
+                ItemKind::Fn(sig, ..) => Some(sig.decl),
+                _ => None,
+            };
+            check_trait_bound_duplication(cx, gen, decl);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        let decl = match item.kind {
+            ImplItemKind::Fn(sig, _) => Some(sig.decl),
+            _ => None,
+        };
+        check_trait_bound_duplication(cx, item.generics, decl);
+    }
+
+    fn check_foreign_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ForeignItem<'tcx>) {
+        if let ForeignItemKind::Fn(decl, _, generics) = item.kind {
+            check_trait_bound_duplication(cx, generics, Some(decl));
         }
     }
 
@@ -166,6 +201,14 @@ fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'tc
                 }
             }
         }
+
+        check_trait_param_duplication(cx, item);
+
+        let decl = match item.kind {
+            TraitItemKind::Fn(sig, _) => Some(sig.decl),
+            _ => None,
+        };
+        check_trait_bound_duplication(cx, item.generics, decl);
     }
 
     fn check_ty(&mut self, cx: &LateContext<'tcx>, ty: &'tcx Ty<'tcx>) {
@@ -228,19 +271,34 @@ impl TraitBounds {
     fn check_type_repetition<'tcx>(self, cx: &LateContext<'tcx>, gen: &'tcx Generics<'_>) {
         struct SpanlessTy<'cx, 'tcx> {
             ty: &'tcx Ty<'tcx>,
+            // The lowered type, if it contains projections. `T::Assoc` and `<T as Trait>::Assoc`
+            // are written differently but lower to the same projection.
+            projection: Option<ty::Ty<'tcx>>,
             cx: &'cx LateContext<'tcx>,
         }
         impl PartialEq for SpanlessTy<'_, '_> {
             fn eq(&self, other: &Self) -> bool {
-                let mut eq = SpanlessEq::new(self.cx);
-                eq.inter_expr().eq_ty(self.ty, other.ty)
+                match (self.projection, other.projection) {
+                    (Some(left), Some(right)) => left == right,
+                    (None, None) => {
+                        let mut eq = SpanlessEq::new(self.cx);
+                        eq.inter_expr().eq_ty(self.ty, other.ty)
+                    },
+                    _ => false,
+                }
             }
         }
         impl Hash for SpanlessTy<'_, '_> {
             fn hash<H: Hasher>(&self, h: &mut H) {
-                let mut t = SpanlessHash::new(self.cx);
-                t.hash_ty(self.ty);
-                h.write_u64(t.finish());
+                if let Some(projection) = self.projection {
+                    let mut t = FxHasher::default();
+                    projection.hash(&mut t);
+                    h.write_u64(t.finish());
+                } else {
+                    let mut t = SpanlessHash::new(self.cx);
+                    t.hash_ty(self.ty);
+                    h.write_u64(t.finish());
+                }
             }
         }
         impl Eq for SpanlessTy<'_, '_> {}
@@ -256,23 +314,52 @@ impl Eq for SpanlessTy<'_, '_> {}
                 if p.origin != PredicateOrigin::ImplTrait;
                 if p.bounds.len() as u64 <= self.max_trait_bounds;
                 if !p.span.from_expansion();
+                // Types bound under a `for<..>` binder could have escaping bound regions once lowered
+                let projection = p
+                    .bound_generic_params
+                    .is_empty()
+                    .then(|| hir_ty_to_ty(cx.tcx, p.bounded_ty))
+                    .filter(|ty| ty.has_projections());
                 if let Some(ref v) = map.insert(
-                    SpanlessTy { ty: p.bounded_ty, cx },
+                    SpanlessTy { ty: p.bounded_ty, projection, cx },
                     p.bounds.iter().collect::<Vec<_>>()
                 );
 
                 then {
-                    let trait_bounds = v
+                    let hint_string = if p
+                        .bounds
                         .iter()
-                        .copied()
-                        .chain(p.bounds.iter())
-                        .filter_map(get_trait_info_from_bound)
-                        .map(|(_, _, span)| snippet_with_applicability(cx, span, "..", &mut applicability))
-                        .join(" + ");
-                    let hint_string = format!(
-                        "consider combining the bounds: `{}: {trait_bounds}`",
-                        snippet(cx, p.bounded_ty.span, "_"),
-                    );
+                        .all(|bound| {
+                            v.iter()
+                                .any(|previous| is_implied_by(cx, bound, previous) || is_same_outlives(bound, previous))
+                        })
+                    {
+                        "consider removing this predicate, all of its bounds are already required".to_string()
+                    } else {
+                        // Bounds listed in both predicates only need to appear once, and a bound implied
+                        // by a more specific one is dropped, as `Iterator` for `Iterator<Item = u8>`.
+                        // Outlives bounds are kept, so that `T: Trait, T: Trait + 'a` becomes `T: Trait + 'a`
+                        let bounds = v.iter().copied().chain(p.bounds.iter()).collect::<Vec<_>>();
+                        let trait_bounds = bounds
+                            .iter()
+                            .enumerate()
+                            .filter(|&(i, bound)| {
+                                !bounds.iter().enumerate().any(|(j, other)| {
+                                    let implied =
+                                        is_implied_by(cx, bound, other) && (j < i || !is_implied_by(cx, other, bound));
+                                    (i != j && implied) || (j < i && is_same_outlives(bound, other))
+                                })
+                            })
+                            .map(|(_, bound)| {
+                                let span = get_trait_info_from_bound(bound).map_or(bound.span(), |(_, _, span)| span);
+                                snippet_with_applicability(cx, span, "..", &mut applicability)
+                            })
+                            .join(" + ");
+                        format!(
+                            "consider combining the bounds: `{}: {trait_bounds}`",
+                            snippet(cx, p.bounded_ty.span, "_"),
+                        )
+                    };
                     span_lint_and_help(
                         cx,
                         TYPE_REPETITION_IN_BOUNDS,
@@ -287,7 +374,8 @@ impl Eq for SpanlessTy<'_, '_> {}
     }
 }
 
-fn check_trait_bound_duplication(cx: &LateContext<'_>, gen: &'_ Generics<'_>) {
+/// Checks the bounds of `gen` for duplicates, `decl` is the signature if they belong to a function.
+fn check_trait_bound_duplication<'tcx>(cx: &LateContext<'tcx>, gen: &Generics<'_>, decl: Option<&'tcx FnDecl<'tcx>>) {
     if gen.span.from_expansion() {
         return;
     }
@@ -299,20 +387,43 @@ fn check_trait_bound_duplication(cx: &LateContext<'_>, gen: &'_ Generics<'_>) {
     //       |
     // collects each of these where clauses into a set keyed by generic name and comparable trait
     // eg. (T, Clone)
+    //
+    // Predicates from a macro expansion (eg. added by an attribute macro to a hand-written item) are
+    // skipped, as neither they nor the bounds they duplicate can be fixed at the lint site.
+    //
+    // Also keep track of whether the where clause consists of nothing but plain trait bounds on
+    // generics, in which case it can be removed entirely if it merely restates the inline bounds.
+    let mut where_clause_is_plain = true;
     let where_predicates = gen
         .predicates
         .iter()
+        .filter(|pred| pred.in_where_clause())
         .filter_map(|pred| {
             if_chain! {
-                if pred.in_where_clause();
                 if let WherePredicate::BoundPredicate(bound_predicate) = pred;
-                if let TyKind::Path(QPath::Resolved(_, path)) =  bound_predicate.bounded_ty.kind;
+                if !bound_predicate.span.from_expansion();
+                if let TyKind::Path(QPath::Resolved(qself, path)) =  bound_predicate.bounded_ty.kind;
                 then {
-                    return Some(
-                        rollup_traits(cx, bound_predicate.bounds, "these where clauses contain repeated elements")
-                        .into_iter().map(|(trait_ref, _)| (path.res, trait_ref)))
+                    let traits = rollup_traits(
+                        cx,
+                        bound_predicate.bounds,
+                        "these where clauses contain repeated elements",
+                        implied_sized_trait(cx, gen, path.res),
+                    );
+                    // A qualified path like `<T as Trait>::Assoc` is resolved to the associated type and
+                    // its first segment is the trait, so it can't restate the bounds of `T`. Other paths
+                    // like type aliases are keyed by their resolution, which isn't a parameter either,
+                    // so `Alias<T>: Clone` is never compared to `T: Clone` whatever the alias expands to
+                    if qself.is_some() {
+                        where_clause_is_plain = false;
+                        return None;
+                    }
+                    where_clause_is_plain &= bound_predicate.bound_generic_params.is_empty()
+                        && bound_predicate.bounds.iter().all(|bound| matches!(bound, GenericBound::Trait(..)));
+                    return Some(traits.into_iter().map(|(trait_ref, _)| (path.res, trait_ref)))
                 }
             }
+            where_clause_is_plain = false;
             None
         })
         .flatten()
@@ -324,6 +435,13 @@ fn check_trait_bound_duplication(cx: &LateContext<'_>, gen: &'_ Generics<'_>) {
     //            |
     // compare trait bounds keyed by generic name and comparable trait to collected where
     // predicates eg. (T, Clone)
+    //
+    // The where clause copy of a bound on a type parameter that is used by a return-position
+    // `impl Trait` is removed instead, keeping the bound next to the declaration of the parameter.
+    let return_impl_trait_params = decl.map(|decl| return_impl_trait_params(cx, decl)).unwrap_or_default();
+    let mut inline_bounds = FxHashSet::default();
+    let mut duplicated_spans = Vec::new();
+    let mut duplicated_in_where_clause = FxHashSet::default();
     for predicate in gen.predicates.iter().filter(|pred| !pred.in_where_clause()) {
         if_chain! {
             if let WherePredicate::BoundPredicate(bound_predicate) = predicate;
@@ -331,18 +449,361 @@ fn check_trait_bound_duplication(cx: &LateContext<'_>, gen: &'_ Generics<'_>) {
             if !bound_predicate.span.from_expansion();
             if let TyKind::Path(QPath::Resolved(_, path)) =  bound_predicate.bounded_ty.kind;
             then {
-                let traits = rollup_traits(cx, bound_predicate.bounds, "these bounds contain repeated elements");
+                let traits = rollup_traits(
+                    cx,
+                    bound_predicate.bounds,
+                    "these bounds contain repeated elements",
+                    implied_sized_trait(cx, gen, path.res),
+                );
                 for (trait_ref, span) in traits {
                     let key = (path.res, trait_ref);
                     if where_predicates.contains(&key) {
+                        if return_impl_trait_params.contains(&path.res) {
+                            duplicated_in_where_clause.insert(key.clone());
+                        } else {
+                            duplicated_spans.push(span);
+                        }
+                    }
+                    inline_bounds.insert(key);
+                }
+            }
+        }
+    }
+
+    // If every inline bound is restated in the where clause and the where clause contains nothing
+    // else, suggest removing the where clause in one go instead of linting each bound separately.
+    if where_clause_is_plain
+        && !inline_bounds.is_empty()
+        && inline_bounds == where_predicates
+        && !gen.where_clause_span.from_expansion()
+    {
+        span_lint_and_sugg(
+            cx,
+            TRAIT_DUPLICATION_IN_BOUNDS,
+            gen.where_clause_span,
+            "this where clause only restates the inline bounds",
+            "remove the `where` clause",
+            String::new(),
+            Applicability::MachineApplicable,
+        );
+        return;
+    }
+
+    for span in duplicated_spans {
+        span_lint_and_help(
+            cx,
+            TRAIT_DUPLICATION_IN_BOUNDS,
+            span,
+            "this trait bound is already specified in the where clause",
+            None,
+            "consider removing this trait bound",
+        );
+    }
+
+    if !duplicated_in_where_clause.is_empty() {
+        check_where_clause_copies(cx, gen, &duplicated_in_where_clause);
+    }
+}
+
+/// Lints the bounds of the where clause that are already specified inline, suggesting to remove
+/// them from the where clause.
+fn check_where_clause_copies(
+    cx: &LateContext<'_>,
+    gen: &'_ Generics<'_>,
+    duplicated: &FxHashSet<(Res, ComparableTraitRef)>,
+) {
+    for (pos, predicate) in gen.predicates.iter().enumerate() {
+        if_chain! {
+            if predicate.in_where_clause();
+            if let WherePredicate::BoundPredicate(bound_predicate) = predicate;
+            if !bound_predicate.span.from_expansion();
+            if let TyKind::Path(QPath::Resolved(None, path)) = bound_predicate.bounded_ty.kind;
+            then {
+                let copies = bound_predicate
+                    .bounds
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, bound)| {
+                        matches!(bound, GenericBound::Trait(t, _)
+                            if duplicated.contains(&(path.res, into_comparable_trait_ref(&t.trait_ref))))
+                    })
+                    .map(|(bound_pos, _)| bound_pos)
+                    .collect::<Vec<_>>();
+
+                match *copies.as_slice() {
+                    [] => {},
+                    [bound_pos] => span_lint_and_sugg(
+                        cx,
+                        TRAIT_DUPLICATION_IN_BOUNDS,
+                        gen.span_for_bound_removal(pos, bound_pos),
+                        "this trait bound is already specified in the inline bounds",
+                        "remove the bound",
+                        String::new(),
+                        Applicability::MachineApplicable,
+                    ),
+                    _ if copies.len() == bound_predicate.bounds.len() => span_lint_and_sugg(
+                        cx,
+                        TRAIT_DUPLICATION_IN_BOUNDS,
+                        gen.span_for_predicate_removal(pos),
+                        "these trait bounds are already specified in the inline bounds",
+                        "remove the predicate",
+                        String::new(),
+                        Applicability::MachineApplicable,
+                    ),
+                    _ => {
+                        let mut applicability = Applicability::MachineApplicable;
+                        let Some((bounds_span, remaining)) =
+                            remaining_bounds_sugg(cx, bound_predicate.bounds, &copies, &mut applicability)
+                        else {
+                            continue;
+                        };
+                        span_lint_and_sugg(
+                            cx,
+                            TRAIT_DUPLICATION_IN_BOUNDS,
+                            bounds_span,
+                            "these trait bounds are already specified in the inline bounds",
+                            "try",
+                            remaining,
+                            applicability,
+                        );
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Lints bounds in the where clause of a trait impl on an associated type of the trait, which are
+/// already required by the declaration of the associated type, as in
+/// `trait Trait { type A: Clone; }` and `impl Trait for S where Self::A: Clone {}`.
+fn check_assoc_type_bound_duplication<'tcx>(
+    cx: &LateContext<'tcx>,
+    gen: &'tcx Generics<'tcx>,
+    trait_ref: ty::TraitRef<'tcx>,
+) {
+    if gen.span.from_expansion() {
+        return;
+    }
+    for predicate in gen.predicates {
+        if_chain! {
+            if let WherePredicate::BoundPredicate(bound_predicate) = predicate;
+            if bound_predicate.origin == PredicateOrigin::WhereClause;
+            if !bound_predicate.span.from_expansion();
+            if bound_predicate.bound_generic_params.is_empty();
+            // `Self::A` and `<S as Trait>::A` are both lowered to the projection
+            if let ty::Alias(ty::Projection, projection) = *hir_ty_to_ty(cx.tcx, bound_predicate.bounded_ty).kind();
+            // Only the associated types of the implemented trait for the self type of the impl, which
+            // excludes the generic associated types
+            if projection.trait_def_id(cx.tcx) == trait_ref.def_id && projection.substs == trait_ref.substs;
+            then {
+                let declared = cx
+                    .tcx
+                    .explicit_item_bounds(projection.def_id)
+                    .skip_binder()
+                    .iter()
+                    .filter_map(|(predicate, _)| match predicate.kind().skip_binder() {
+                        ty::PredicateKind::Clause(ty::Clause::Trait(trait_predicate))
+                            if trait_predicate.trait_ref.substs.len() == 1 =>
+                        {
+                            Some(trait_predicate.def_id())
+                        },
+                        _ => None,
+                    })
+                    .collect::<FxHashSet<_>>();
+                for bound in bound_predicate.bounds {
+                    // Only plain traits, the declared bounds are compared by trait
+                    if let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound
+                        && poly_trait_ref.bound_generic_params.is_empty()
+                        && poly_trait_ref.trait_ref.path.segments.iter().all(|segment| segment.args.is_none())
+                        && let Some(trait_id) = poly_trait_ref.trait_ref.trait_def_id()
+                        && declared.contains(&trait_id)
+                    {
+                        span_lint_and_help(
+                            cx,
+                            TRAIT_DUPLICATION_IN_BOUNDS,
+                            poly_trait_ref.span,
+                            "this trait bound is already specified in the declaration of the associated type",
+                            None,
+                            "consider removing this trait bound",
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the type parameters named in the bounds of a return-position `impl Trait` of the
+/// function, if any.
+fn return_impl_trait_params<'tcx>(cx: &LateContext<'tcx>, decl: &'tcx FnDecl<'tcx>) -> FxHashSet<Res> {
+    let mut visitor = ReturnImplTraitParams {
+        cx,
+        in_impl_trait: false,
+        params: FxHashSet::default(),
+    };
+    if let FnRetTy::Return(ty) = decl.output {
+        visitor.visit_ty(ty);
+    }
+    visitor.params
+}
+
+struct ReturnImplTraitParams<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    in_impl_trait: bool,
+    params: FxHashSet<Res>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ReturnImplTraitParams<'a, 'tcx> {
+    fn visit_ty(&mut self, ty: &'tcx Ty<'tcx>) {
+        if let TyKind::OpaqueDef(item_id, ..) = ty.kind
+            && let ItemKind::OpaqueTy(opaque) = self.cx.tcx.hir().item(item_id).kind
+            && matches!(opaque.origin, OpaqueTyOrigin::FnReturn(_))
+        {
+            let in_impl_trait = mem::replace(&mut self.in_impl_trait, true);
+            for bound in opaque.bounds {
+                walk_param_bound(self, bound);
+            }
+            self.in_impl_trait = in_impl_trait;
+        }
+        walk_ty(self, ty);
+    }
+
+    fn visit_path(&mut self, path: &Path<'tcx>, _: HirId) {
+        if self.in_impl_trait && matches!(path.res, Res::Def(DefKind::TyParam, _)) {
+            self.params.insert(path.res);
+        }
+        walk_path(self, path);
+    }
+}
+
+/// Lints bounds of where clause predicates on trait object types that are already part of the
+/// object type, as in `where dyn Any + Send: Send`.
+///
+/// Such predicates aren't rolled up by `check_trait_bound_duplication`, which only considers bounds
+/// on paths, so a single suggestion can rewrite the whole list of bounds without conflicting with
+/// it.
+fn check_trait_object_predicates(cx: &LateContext<'_>, gen: &'_ Generics<'_>) {
+    if gen.span.from_expansion() {
+        return;
+    }
+
+    for (pos, predicate) in gen.predicates.iter().enumerate() {
+        if_chain! {
+            if let WherePredicate::BoundPredicate(bound_predicate) = predicate;
+            if !bound_predicate.span.from_expansion();
+            if let TyKind::TraitObject(object_bounds, ..) = bound_predicate.bounded_ty.kind;
+            then {
+                let object_traits = object_bounds
+                    .iter()
+                    .map(|poly_trait_ref| into_comparable_trait_ref(&poly_trait_ref.trait_ref))
+                    .collect::<FxHashSet<_>>();
+                let redundant = bound_predicate
+                    .bounds
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, bound)| {
+                        comparable_bound(bound).map_or(false, |(trait_ref, modifier)| {
+                            modifier == TraitBoundModifier::None && object_traits.contains(&trait_ref)
+                        })
+                    })
+                    .map(|(bound_pos, _)| bound_pos)
+                    .collect::<Vec<_>>();
+                if redundant.is_empty() {
+                    continue;
+                }
+
+                if redundant.len() == bound_predicate.bounds.len() {
+                    span_lint_and_sugg(
+                        cx,
+                        TRAIT_DUPLICATION_IN_BOUNDS,
+                        gen.span_for_predicate_removal(pos),
+                        "this predicate only restates bounds of the trait object type",
+                        "remove the predicate",
+                        String::new(),
+                        Applicability::MachineApplicable,
+                    );
+                } else {
+                    let mut applicability = Applicability::MachineApplicable;
+                    let Some((bounds_span, traits)) =
+                        remaining_bounds_sugg(cx, bound_predicate.bounds, &redundant, &mut applicability)
+                    else {
+                        continue;
+                    };
+                    span_lint_and_sugg(
+                        cx,
+                        TRAIT_DUPLICATION_IN_BOUNDS,
+                        bounds_span,
+                        "these bounds are already part of the trait object type",
+                        "try",
+                        traits,
+                        applicability,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Lints bounds in the generics of a trait item on a generic parameter of the trait or on `Self`,
+/// which are already specified in the generics of the trait, as in
+/// `trait Trait<X: Clone> { fn f() where X: Clone; }` or
+/// `trait Trait where Self: Clone { type A where Self: Clone; }`.
+fn check_trait_param_duplication<'tcx>(cx: &LateContext<'tcx>, item: &'tcx TraitItem<'tcx>) {
+    if item.span.from_expansion() {
+        return;
+    }
+    let parent = cx.tcx.hir().get_parent_item(item.hir_id());
+    let Node::Item(Item {
+        kind: ItemKind::Trait(_, _, trait_generics, ..),
+        ..
+    }) = cx.tcx.hir().get_by_def_id(parent.def_id)
+    else {
+        return;
+    };
+
+    // Collects the bounds on the generic parameters of the trait and on `Self`, keyed by parameter
+    // and trait
+    let mut param_bounds: FxHashMap<(Res, Res), Vec<&[PathSegment<'_>]>> = FxHashMap::default();
+    for predicate in trait_generics.predicates {
+        if_chain! {
+            if let WherePredicate::BoundPredicate(bound_predicate) = predicate;
+            if !bound_predicate.span.from_expansion();
+            if let TyKind::Path(QPath::Resolved(_, path)) = bound_predicate.bounded_ty.kind;
+            if let Res::Def(DefKind::TyParam, _) | Res::SelfTyParam { .. } = path.res;
+            then {
+                for (trait_res, segments, _) in bound_predicate.bounds.iter().filter_map(get_trait_info_from_bound) {
+                    param_bounds.entry((path.res, trait_res)).or_default().push(segments);
+                }
+            }
+        }
+    }
+    if param_bounds.is_empty() {
+        return;
+    }
+
+    for predicate in item.generics.predicates {
+        if_chain! {
+            if let WherePredicate::BoundPredicate(bound_predicate) = predicate;
+            if bound_predicate.origin != PredicateOrigin::ImplTrait;
+            if !bound_predicate.span.from_expansion();
+            if let TyKind::Path(QPath::Resolved(_, path)) = bound_predicate.bounded_ty.kind;
+            then {
+                for (trait_res, segments, span) in bound_predicate.bounds.iter().filter_map(get_trait_info_from_bound) {
+                    let Some(trait_segments) = param_bounds.get(&(path.res, trait_res)) else {
+                        continue;
+                    };
+                    if trait_segments
+                        .iter()
+                        .any(|trait_segments| SpanlessEq::new(cx).eq_path_segments(trait_segments, segments))
+                    {
                         span_lint_and_help(
                             cx,
                             TRAIT_DUPLICATION_IN_BOUNDS,
                             span,
-                            "this trait bound is already specified in the where clause",
+                            "this trait bound is already specified in the generics of the trait",
                             None,
                             "consider removing this trait bound",
-                            );
+                        );
                     }
                 }
             }
@@ -350,11 +811,22 @@ fn check_trait_bound_duplication(cx: &LateContext<'_>, gen: &'_ Generics<'_>) {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
-struct ComparableTraitRef(Res, Vec<Res>);
+/// A trait reference identified by its trait, its type arguments and its lifetime arguments,
+/// ignoring spans. Lifetimes are compared by their resolution, like `SpanlessEq` does, so that
+/// `Trait<'a>` and `Trait<'b>` are different bounds.
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct ComparableTraitRef(Res, Vec<Res>, Vec<LifetimeName>);
 impl Default for ComparableTraitRef {
     fn default() -> Self {
-        Self(Res::Err, Vec::new())
+        Self(Res::Err, Vec::new(), Vec::new())
+    }
+}
+// `LifetimeName` isn't hashable. Leaving the lifetimes out of the hash is fine, as equal trait
+// references still hash the same.
+impl Hash for ComparableTraitRef {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.0.hash(h);
+        self.1.hash(h);
     }
 }
 
@@ -396,32 +868,133 @@ fn into_comparable_trait_ref(trait_ref: &TraitRef<'_>) -> ComparableTraitRef {
             })
             .flatten()
             .collect(),
+        trait_ref
+            .path
+            .segments
+            .iter()
+            .filter_map(|segment| {
+                // get trait bound lifetime arguments
+                Some(segment.args?.args.iter().filter_map(|arg| match arg {
+                    GenericArg::Lifetime(lifetime) => Some(lifetime.res),
+                    _ => None,
+                }))
+            })
+            .flatten()
+            .collect(),
     )
 }
 
-fn rollup_traits(cx: &LateContext<'_>, bounds: &[GenericBound<'_>], msg: &str) -> Vec<(ComparableTraitRef, Span)> {
-    let mut map = FxHashMap::default();
-    let mut repeated_res = false;
+/// Identifies a trait bound by its trait, type arguments and modifier, ignoring spans.
+fn comparable_bound(bound: &GenericBound<'_>) -> Option<(ComparableTraitRef, TraitBoundModifier)> {
+    if let GenericBound::Trait(t, modifier) = bound {
+        Some((into_comparable_trait_ref(&t.trait_ref), *modifier))
+    } else {
+        None
+    }
+}
 
-    let only_comparable_trait_refs = |bound: &GenericBound<'_>| {
-        if let GenericBound::Trait(t, _) = bound {
-            Some((into_comparable_trait_ref(&t.trait_ref), t.span))
-        } else {
-            None
-        }
+/// Whether the bound is implied by `other`: both bounds are on the same trait, and the bound has
+/// either the same associated type bindings as `other` or none at all, like `Iterator` is implied
+/// by `Iterator<Item = u8>`.
+fn is_implied_by(cx: &LateContext<'_>, bound: &GenericBound<'_>, other: &GenericBound<'_>) -> bool {
+    let (GenericBound::Trait(poly_trait_ref, _), GenericBound::Trait(other_poly_trait_ref, _)) = (bound, other) else {
+        return false;
     };
+    if comparable_bound(bound).is_none() || comparable_bound(bound) != comparable_bound(other) {
+        return false;
+    }
+    let segments = poly_trait_ref.trait_ref.path.segments;
+    let has_bindings = segments
+        .iter()
+        .any(|segment| segment.args.map_or(false, |args| !args.bindings.is_empty()));
+    !has_bindings || SpanlessEq::new(cx).eq_path_segments(segments, other_poly_trait_ref.trait_ref.path.segments)
+}
+
+/// Whether both bounds are outlives bounds on the same lifetime, like the two `'a` in
+/// `T: Clone + 'a, T: Default + 'a`.
+fn is_same_outlives(bound: &GenericBound<'_>, other: &GenericBound<'_>) -> bool {
+    matches!(
+        (bound, other),
+        (GenericBound::Outlives(lifetime), GenericBound::Outlives(other_lifetime)) if lifetime.res == other_lifetime.res
+    )
+}
+
+/// Returns the `Sized` trait if the bounded type is a type parameter declared by the generics that
+/// isn't bound by `?Sized`, so that a `Sized` bound is implied for it. The parameters of a parent,
+/// like the impl of a method, can be bound by `?Sized` there.
+fn implied_sized_trait(cx: &LateContext<'_>, gen: &Generics<'_>, res: Res) -> Option<DefId> {
+    let Res::Def(DefKind::TyParam, param_id) = res else {
+        return None;
+    };
+    if !gen.params.iter().any(|param| param.def_id.to_def_id() == param_id) {
+        return None;
+    }
+    let is_maybe_sized = gen.predicates.iter().any(|predicate| {
+        matches!(predicate, WherePredicate::BoundPredicate(bound_predicate)
+            if bound_predicate.bounded_ty.as_generic_param().map(|(id, _)| id) == Some(param_id)
+                && bound_predicate
+                    .bounds
+                    .iter()
+                    .any(|bound| matches!(bound, GenericBound::Trait(_, TraitBoundModifier::Maybe))))
+    });
+    if is_maybe_sized {
+        None
+    } else {
+        cx.tcx.lang_items().sized_trait()
+    }
+}
+
+/// Lints repeated bounds in the list, suggesting to keep the first of each. If the `implied` trait,
+/// like `Sized` for type parameters, is repeated, the suggestion drops all of its bounds as long as
+/// other bounds remain.
+fn rollup_traits(
+    cx: &LateContext<'_>,
+    bounds: &[GenericBound<'_>],
+    msg: &str,
+    implied: Option<DefId>,
+) -> Vec<(ComparableTraitRef, Span)> {
+    let mut map = FxHashMap::default();
+    // `LifetimeName` isn't hashable, and there are rarely more than a few outlives bounds
+    let mut lifetimes = Vec::new();
+    // The spans of the bounds that are kept in the suggestion, in source order, and whether they
+    // are bounds of the implied trait
+    let mut kept_spans = Vec::new();
+    let mut repeated_res = false;
+    let mut repeated_implied = false;
 
     let mut i = 0usize;
-    for bound in bounds.iter().filter_map(only_comparable_trait_refs) {
-        let (comparable_bound, span_direct) = bound;
-        match map.entry(comparable_bound) {
-            Entry::Occupied(_) => repeated_res = true,
-            Entry::Vacant(e) => {
-                e.insert((span_direct, i));
-                i += 1;
+    for bound in bounds {
+        match bound {
+            GenericBound::Trait(t, modifier) => {
+                let is_implied =
+                    *modifier == TraitBoundModifier::None && implied.is_some() && t.trait_ref.trait_def_id() == implied;
+                match map.entry(into_comparable_trait_ref(&t.trait_ref)) {
+                    Entry::Occupied(_) => {
+                        repeated_res = true;
+                        repeated_implied |= is_implied;
+                    },
+                    Entry::Vacant(e) => {
+                        e.insert((t.span, i));
+                        kept_spans.push((t.span, is_implied));
+                        i += 1;
+                    },
+                }
             },
+            GenericBound::Outlives(lifetime) => {
+                if lifetimes.contains(&lifetime.res) {
+                    repeated_res = true;
+                } else {
+                    lifetimes.push(lifetime.res);
+                    kept_spans.push((bound.span(), false));
+                }
+            },
+            GenericBound::LangItemTrait(..) => kept_spans.push((bound.span(), false)),
         }
     }
+    // A bound that is implied anyway doesn't need to be kept once, unless it's the only one
+    if repeated_implied && kept_spans.iter().any(|&(_, is_implied)| !is_implied) {
+        kept_spans.retain(|&(_, is_implied)| !is_implied);
+    }
 
     // Put bounds in source order
     let mut comparable_bounds = vec![Default::default(); map.len()];
@@ -432,11 +1005,14 @@ fn rollup_traits(cx: &LateContext<'_>, bounds: &[GenericBound<'_>], msg: &str) -
     if_chain! {
         if repeated_res;
         if let [first_trait, .., last_trait] = bounds;
+        // the suggestion rewrites the whole list of bounds, which is only possible if none of them
+        // come from a macro expansion
+        if !bounds.iter().any(|bound| bound.span().from_expansion());
         then {
             let all_trait_span = first_trait.span().to(last_trait.span());
 
-            let traits = comparable_bounds.iter()
-                .filter_map(|&(_, span)| snippet_opt(cx, span))
+            let traits = kept_spans.iter()
+                .filter_map(|&(span, _)| snippet_opt(cx, span))
                 .collect::<Vec<_>>();
             let traits = traits.join(" + ");
 
@@ -0,0 +1,188 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::trait_ref_of_method;
+use clippy_utils::ty::{param_index, signature_param_uses};
+use clippy_utils::visitors::for_each_expr;
+use core::ops::ControlFlow;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{
+    BodyId, GenericBound, Generics, ImplItem, ImplItemKind, Item, ItemKind, PredicateOrigin, TraitBoundModifier,
+    WherePredicate,
+};
+use rustc_lint::{LateContext, LateLintPass, Lint};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Send` and `Sync` bounds on the type parameters of functions, where the type
+    /// parameter is never used in the body of the function.
+    ///
+    /// ### Why is this bad?
+    /// A value of the type parameter that is never used can't be sent to another thread, so the
+    /// bound only restricts the callers of the function.
+    ///
+    /// ### Known problems
+    /// The check is very conservative: a type parameter counts as used as soon as any expression
+    /// of the body, or any type of the signature other than the parameter itself or a reference to
+    /// it, mentions it.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn log<T: Send>(_value: T) {
+    ///     println!("called");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn log<T>(_value: T) {
+    ///     println!("called");
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub UNUSED_SEND_SYNC_BOUND,
+    pedantic,
+    "`Send` or `Sync` bounds on type parameters of functions that never use the parameter"
+}
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for trait bounds on the type parameters of private functions, where the type
+    /// parameter is never used in the body of the function.
+    ///
+    /// ### Why is this bad?
+    /// The body can't rely on the bound without using the type parameter, so the bound only
+    /// restricts the callers of the function. Private functions are not part of the public API of
+    /// the crate, so the bound can be removed without breaking anyone.
+    ///
+    /// ### Known problems
+    /// The check is as conservative as the one of `unused_send_sync_bound`, which already covers
+    /// `Send` and `Sync` bounds.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn log<T: Clone>(_value: T) {
+    ///     println!("called");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn log<T>(_value: T) {
+    ///     println!("called");
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub UNUSED_PRIVATE_FN_BOUND,
+    pedantic,
+    "trait bounds on type parameters of private functions that never use the parameter"
+}
+declare_lint_pass!(UnusedFnBounds => [UNUSED_SEND_SYNC_BOUND, UNUSED_PRIVATE_FN_BOUND]);
+
+fn check_fn<'tcx>(cx: &LateContext<'tcx>, def_id: LocalDefId, generics: &'tcx Generics<'tcx>, body_id: BodyId) {
+    let Some(mut uses) = signature_param_uses(cx.tcx, def_id.to_def_id()) else {
+        return;
+    };
+    if !uses.other_predicates.is_empty() {
+        return;
+    }
+
+    // Closures mention all the type parameters of the function in their type, so there is no need
+    // to visit their bodies
+    let typeck = cx.tcx.typeck_body(body_id);
+    let _: Option<!> = for_each_expr(cx.tcx.hir().body(body_id), |expr| {
+        if let Some(ty) = typeck.expr_ty_opt(expr) {
+            uses.used.extend(ty.walk().filter_map(param_index));
+        }
+        if let Some(substs) = typeck.node_substs_opt(expr.hir_id) {
+            uses.used
+                .extend(substs.iter().flat_map(|arg| arg.walk()).filter_map(param_index));
+        }
+        ControlFlow::Continue(())
+    });
+
+    let generics_of = cx.tcx.generics_of(def_id);
+    let is_exported = cx.effective_visibilities.is_exported(def_id);
+    let emit = |lint: &'static Lint, span: Span| {
+        span_lint_and_help(
+            cx,
+            lint,
+            span,
+            "this bound is not needed, as the type parameter is never used by the function",
+            None,
+            "consider removing the bound",
+        );
+    };
+    for (pred_pos, predicate) in generics.predicates.iter().enumerate() {
+        let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+            continue;
+        };
+        if bound_predicate.origin == PredicateOrigin::ImplTrait
+            || bound_predicate.span.from_expansion()
+            || !bound_predicate.bound_generic_params.is_empty()
+        {
+            continue;
+        }
+        let Some((param_id, _)) = bound_predicate.bounded_ty.as_generic_param() else {
+            continue;
+        };
+        let Some(index) = generics_of.param_def_id_to_index(cx.tcx, param_id) else {
+            continue;
+        };
+        if uses.used.contains(&index) {
+            continue;
+        }
+
+        let unneeded = bound_predicate
+            .bounds
+            .iter()
+            .enumerate()
+            .filter_map(|(bound_pos, bound)| {
+                let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound else {
+                    return None;
+                };
+                let Res::Def(DefKind::Trait, trait_id) = poly_trait_ref.trait_ref.path.res else {
+                    return None;
+                };
+                if cx.tcx.is_diagnostic_item(sym::Send, trait_id) || cx.tcx.is_diagnostic_item(sym::Sync, trait_id) {
+                    Some((bound_pos, UNUSED_SEND_SYNC_BOUND))
+                } else if !is_exported {
+                    Some((bound_pos, UNUSED_PRIVATE_FN_BOUND))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Removing several bounds of a predicate one by one could produce overlapping spans, so
+        // point at the whole predicate if all of its bounds are unneeded for the same reason
+        if let [(_, lint), rest @ ..] = unneeded.as_slice()
+            && unneeded.len() == bound_predicate.bounds.len()
+            && rest.iter().all(|(_, other)| other.name == lint.name)
+        {
+            emit(*lint, generics.span_for_predicate_removal(pred_pos));
+        } else {
+            for (bound_pos, lint) in unneeded {
+                emit(lint, generics.span_for_bound_removal(pred_pos, bound_pos));
+            }
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for UnusedFnBounds {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Fn(_, generics, body_id) = item.kind
+            && !item.span.from_expansion()
+        {
+            check_fn(cx, item.owner_id.def_id, generics, body_id);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        // Only lint on inherent methods, trait methods have to match the bounds of the trait.
+        if let ImplItemKind::Fn(_, body_id) = item.kind
+            && trait_ref_of_method(cx, item.owner_id.def_id).is_none()
+            && !item.span.from_expansion()
+        {
+            check_fn(cx, item.owner_id.def_id, item.generics, body_id);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::{param_index, signature_param_uses};
+use clippy_utils::{bound_removal_spans, trait_ref_of_method};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::{walk_expr, Visitor};
+use rustc_hir::{
+    BodyId, Expr, ExprKind, GenericBound, Generics, ImplItem, ImplItemKind, Item, ItemKind, PredicateOrigin,
+    TraitBoundModifier, WherePredicate,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::ty::adjustment::{Adjust, PointerCast};
+use rustc_middle::ty::{self, AssocKind, Ty, TypeckResults};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+use rustc_trait_selection::traits::supertrait_def_ids;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for trait bounds on the type parameters of functions, where the function never
+    /// uses any item of the trait.
+    ///
+    /// ### Why is this bad?
+    /// The bound only restricts the callers of the function without being needed by it.
+    ///
+    /// ### Known problems
+    /// The check is conservative: bounds are only linted if the type parameter is never passed
+    /// to anything that could require the bound, such as another generic function, a generic
+    /// type or a trait object. Bounds on traits without methods, like `Copy`, are never linted.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn first<T: Clone>(items: &[T]) -> Option<&T> {
+    ///     items.first()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn first<T>(items: &[T]) -> Option<&T> {
+    ///     items.first()
+    /// }
+    /// ```
+    #[clippy::version = "1.71.0"]
+    pub UNUSED_TRAIT_BOUND,
+    pedantic,
+    "trait bounds on type parameters of functions that never use any item of the trait"
+}
+declare_lint_pass!(UnusedTraitBound => [UNUSED_TRAIT_BOUND]);
+
+/// Whether the type is a type parameter, or a reference to one. Such a type can be moved around
+/// without requiring any bound of the parameter.
+fn is_plain_param(ty: Ty<'_>) -> bool {
+    matches!(ty.peel_refs().kind(), ty::Param(_))
+}
+
+/// A visitor struct that walks a function body, collecting the traits whose items are used and
+/// the type parameters that could need their bounds for something other than using those items.
+struct BoundUses<'cx, 'tcx> {
+    cx: &'cx LateContext<'tcx>,
+    typeck: &'tcx TypeckResults<'tcx>,
+    /// Traits of the associated items called or named in the body.
+    used_traits: FxHashSet<DefId>,
+    /// Indices of the type parameters that appear in a non-plain type, or in the generic arguments
+    /// of a path or method call.
+    escaping: FxHashSet<u32>,
+}
+
+impl<'cx, 'tcx> BoundUses<'cx, 'tcx> {
+    fn escape(&mut self, ty: Ty<'tcx>) {
+        self.escaping.extend(ty.walk().filter_map(param_index));
+    }
+}
+
+impl<'cx, 'tcx> Visitor<'tcx> for BoundUses<'cx, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        let def_id = match expr.kind {
+            ExprKind::Path(ref qpath) => self.typeck.qpath_res(qpath, expr.hir_id).opt_def_id(),
+            _ => self.typeck.type_dependent_def_id(expr.hir_id),
+        };
+        let trait_item = def_id.and_then(|def_id| Some((def_id, self.cx.tcx.trait_of_item(def_id)?)));
+        if let Some((_, trait_id)) = trait_item {
+            self.used_traits.insert(trait_id);
+        }
+
+        if let Some(substs) = self.typeck.node_substs_opt(expr.hir_id) {
+            // Using a trait item on a type parameter only requires the parameter to implement
+            // that trait, unless the item has further requirements of its own
+            let uses_item_on_param = trait_item.map_or(false, |(def_id, _)| {
+                self.cx.tcx.predicates_of(def_id).predicates.is_empty()
+                    && substs.first().and_then(|arg| param_index(*arg)).is_some()
+            });
+            let substs = if uses_item_on_param { &substs[1..] } else { &substs[..] };
+            self.escaping
+                .extend(substs.iter().flat_map(|arg| arg.walk()).filter_map(param_index));
+        }
+
+        if let Some(ty) = self.typeck.expr_ty_opt(expr) {
+            // Coercing a value to a trait object can require the bound without naming any item
+            let coerces_to_dyn = self
+                .typeck
+                .expr_adjustments(expr)
+                .iter()
+                .any(|adjustment| matches!(adjustment.kind, Adjust::Pointer(PointerCast::Unsize)));
+            // The generic arguments of function items are the `substs` handled above
+            if coerces_to_dyn || !(is_plain_param(ty) || matches!(ty.kind(), ty::FnDef(..))) {
+                self.escape(ty);
+            }
+        }
+
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+}
+
+fn check_fn<'tcx>(cx: &LateContext<'tcx>, def_id: LocalDefId, generics: &'tcx Generics<'tcx>, body_id: BodyId) {
+    // The bounds can be needed for the hidden type of an `impl Trait` in return position, or by
+    // the predicates other than the trait bounds on the type parameters
+    let Some(signature_uses) = signature_param_uses(cx.tcx, def_id.to_def_id()) else {
+        return;
+    };
+    if !signature_uses.other_predicates.is_empty() {
+        return;
+    }
+
+    let typeck = cx.tcx.typeck_body(body_id);
+    let mut uses = BoundUses {
+        cx,
+        typeck,
+        used_traits: FxHashSet::default(),
+        escaping: signature_uses.used,
+    };
+
+    uses.visit_nested_body(body_id);
+
+    let generics_of = cx.tcx.generics_of(def_id);
+    let emit = |span: Span| {
+        span_lint_and_help(
+            cx,
+            UNUSED_TRAIT_BOUND,
+            span,
+            "this bound is not used by the function",
+            None,
+            "consider removing the bound",
+        );
+    };
+    for (pred_pos, predicate) in generics.predicates.iter().enumerate() {
+        let WherePredicate::BoundPredicate(bound_predicate) = predicate else {
+            continue;
+        };
+        if bound_predicate.origin == PredicateOrigin::ImplTrait
+            || bound_predicate.span.from_expansion()
+            || !bound_predicate.bound_generic_params.is_empty()
+        {
+            continue;
+        }
+        let Some((param_id, _)) = bound_predicate.bounded_ty.as_generic_param() else {
+            continue;
+        };
+        let Some(index) = generics_of.param_def_id_to_index(cx.tcx, param_id) else {
+            continue;
+        };
+        if uses.escaping.contains(&index) {
+            continue;
+        }
+
+        let unused = bound_predicate
+            .bounds
+            .iter()
+            .enumerate()
+            .filter(|(_, bound)| {
+                let GenericBound::Trait(poly_trait_ref, TraitBoundModifier::None) = bound else {
+                    return false;
+                };
+                let Res::Def(DefKind::Trait, trait_id) = poly_trait_ref.trait_ref.path.res else {
+                    return false;
+                };
+                // Marker traits can be needed without naming any of their items, e.g. `Copy`
+                let has_methods = cx
+                    .tcx
+                    .associated_items(trait_id)
+                    .in_definition_order()
+                    .any(|item| item.kind == AssocKind::Fn);
+                has_methods
+                    && poly_trait_ref.bound_generic_params.is_empty()
+                    && !supertrait_def_ids(cx.tcx, trait_id).any(|id| uses.used_traits.contains(&id))
+            })
+            .map(|(bound_pos, _)| bound_pos)
+            .collect::<Vec<_>>();
+
+        for span in bound_removal_spans(generics, pred_pos, &unused) {
+            emit(span);
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for UnusedTraitBound {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Fn(_, generics, body_id) = item.kind
+            && !item.span.from_expansion()
+        {
+            check_fn(cx, item.owner_id.def_id, generics, body_id);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        // Only lint on inherent methods, trait methods have to match the bounds of the trait.
+        if let ImplItemKind::Fn(_, body_id) = item.kind
+            && trait_ref_of_method(cx, item.owner_id.def_id).is_none()
+            && !item.span.from_expansion()
+        {
+            check_fn(cx, item.owner_id.def_id, item.generics, body_id);
+        }
+    }
+}
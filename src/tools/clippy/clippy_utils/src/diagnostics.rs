@@ -9,11 +9,13 @@
 //! ~The `INTERNAL_METADATA_COLLECTOR` lint
 
 use rustc_errors::{Applicability, Diagnostic, MultiSpan};
-use rustc_hir::HirId;
+use rustc_hir::{Generics, HirId};
 use rustc_lint::{LateContext, Lint, LintContext};
 use rustc_span::source_map::Span;
 use std::env;
 
+use crate::bound_removal_spans;
+
 fn docs_link(diag: &mut Diagnostic, lint: &'static Lint) {
     if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
         if let Some(lint) = lint.name_lower().strip_prefix("clippy::") {
@@ -208,6 +210,31 @@ pub fn span_lint_and_sugg<T: LintContext>(
     });
 }
 
+/// Emits `lint` with a suggestion removing each of the bounds at `bound_positions` from the where
+/// predicate at `pred_pos` of `generics`, or the whole predicate if none of its bounds is needed.
+///
+/// See [`bound_removal_spans`].
+pub fn span_lint_and_remove_bounds<T: LintContext>(
+    cx: &T,
+    lint: &'static Lint,
+    generics: &Generics<'_>,
+    pred_pos: usize,
+    bound_positions: &[usize],
+    msg: &str,
+) {
+    for span in bound_removal_spans(generics, pred_pos, bound_positions) {
+        span_lint_and_sugg(
+            cx,
+            lint,
+            span,
+            msg,
+            "remove the bound",
+            String::new(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
 /// Create a suggestion made from several `span → replacement`.
 ///
 /// Note: in the JSON format (used by `compiletest_rs`), the help message will
@@ -82,6 +82,7 @@
 use rustc_ast::Attribute;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::unhash::UnhashMap;
+use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::def_id::{CrateNum, DefId, LocalDefId, LOCAL_CRATE};
 use rustc_hir::hir_id::{HirIdMap, HirIdSet};
@@ -117,6 +118,7 @@
 
 use crate::consts::{constant, miri_to_const, Constant};
 use crate::higher::Range;
+use crate::source::snippet_with_applicability;
 use crate::ty::{can_partially_move_ty, expr_sig, is_copy, is_recursively_primitive_type, ty_is_fn_once_param};
 use crate::visitors::for_each_expr;
 
@@ -2529,6 +2531,50 @@ pub fn span_find_starting_semi(sm: &SourceMap, span: Span) -> Span {
     sm.span_take_while(span, |&ch| ch == ' ' || ch == ';')
 }
 
+/// Checks if any segment of the path has generic arguments, e.g. `AsRef<str>` or
+/// `Iterator<Item = u8>`.
+pub fn has_generic_args(path: &Path<'_>) -> bool {
+    path.segments.iter().any(|segment| segment.args.is_some())
+}
+
+/// Gets the spans removing the bounds at `bound_positions` from the where predicate at `pred_pos`
+/// of `generics`, in the order of `bound_positions`.
+///
+/// Removing several bounds of a predicate one by one could produce overlapping suggestions, so a
+/// single span removing the whole predicate is returned if none of its bounds is needed.
+pub fn bound_removal_spans(generics: &hir::Generics<'_>, pred_pos: usize, bound_positions: &[usize]) -> Vec<Span> {
+    if !bound_positions.is_empty() && bound_positions.len() == generics.predicates[pred_pos].bounds().len() {
+        vec![generics.span_for_predicate_removal(pred_pos)]
+    } else {
+        bound_positions
+            .iter()
+            .map(|&bound_pos| generics.span_for_bound_removal(pred_pos, bound_pos))
+            .collect()
+    }
+}
+
+/// Gets the span of the `bounds` of a predicate, along with a suggestion keeping only the bounds
+/// not at `bound_positions`, e.g. `Clone + Copy + Default` without its second bound is rewritten
+/// to `Clone + Default`.
+///
+/// This is used instead of removing the bounds one by one, which could produce overlapping
+/// suggestions, when several bounds are removed and some are kept.
+pub fn remaining_bounds_sugg(
+    cx: &LateContext<'_>,
+    bounds: hir::GenericBounds<'_>,
+    bound_positions: &[usize],
+    applicability: &mut Applicability,
+) -> Option<(Span, String)> {
+    let span = bounds.iter().map(hir::GenericBound::span).reduce(Span::to)?;
+    let sugg = bounds
+        .iter()
+        .enumerate()
+        .filter(|(bound_pos, _)| !bound_positions.contains(bound_pos))
+        .map(|(_, bound)| snippet_with_applicability(cx, bound.span(), "..", applicability))
+        .join(" + ");
+    Some((span, sugg))
+}
+
 macro_rules! op_utils {
     ($($name:ident $assign:ident)*) => {
         /// Binary operation traits like `LangItem::Add`
@@ -217,6 +217,91 @@ pub fn implements_trait<'tcx>(
     )
 }
 
+/// Gets the index of the type parameter, if the generic argument is one.
+pub fn param_index(arg: GenericArg<'_>) -> Option<u32> {
+    match *arg.as_type()?.kind() {
+        ty::Param(param) => Some(param.index),
+        _ => None,
+    }
+}
+
+/// How the signature of a function uses its type parameters, see [`signature_param_uses`].
+pub struct SignatureParamUses<'tcx> {
+    /// Indices of the type parameters which the signature can need the bounds of. They appear in
+    /// the types of the inputs and output, as something other than the type parameter itself or
+    /// a reference to it, which must be well-formed, or in the generic arguments of the trait
+    /// predicates.
+    pub used: FxHashSet<u32>,
+    /// The trait predicates on the type parameters, with the index of the parameter.
+    pub trait_predicates: Vec<(u32, ty::TraitPredicate<'tcx>)>,
+    /// The predicates other than the trait predicates on the type parameters and the outlives
+    /// predicates, such as projection predicates, which can need the bounds of the type
+    /// parameters.
+    pub other_predicates: Vec<PredicateKind<'tcx>>,
+}
+
+/// Collects how the signature of a function uses its type parameters, to tell whether their
+/// bounds are needed. Returns `None` if the function returns an `impl Trait`, as its hidden type
+/// can capture the type parameters.
+pub fn signature_param_uses<'tcx>(tcx: TyCtxt<'tcx>, def_id: DefId) -> Option<SignatureParamUses<'tcx>> {
+    let fn_sig = tcx.fn_sig(def_id).subst_identity().skip_binder();
+    if fn_sig.output().walk().any(|arg| {
+        arg.as_type()
+            .map_or(false, |ty| matches!(ty.kind(), ty::Alias(ty::Opaque, _)))
+    }) {
+        return None;
+    }
+
+    let mut uses = SignatureParamUses {
+        used: FxHashSet::default(),
+        trait_predicates: Vec::new(),
+        other_predicates: Vec::new(),
+    };
+    for &ty in fn_sig.inputs_and_output {
+        if !matches!(ty.peel_refs().kind(), ty::Param(_)) {
+            uses.used.extend(ty.walk().filter_map(param_index));
+        }
+    }
+    for (predicate, _) in tcx.predicates_of(def_id).predicates {
+        match predicate.kind().skip_binder() {
+            PredicateKind::Clause(ty::Clause::Trait(trait_predicate))
+                if let Some(index) = param_index(trait_predicate.self_ty().into()) =>
+            {
+                uses.used.extend(
+                    trait_predicate.trait_ref.substs[1..]
+                        .iter()
+                        .flat_map(|arg| arg.walk())
+                        .filter_map(param_index),
+                );
+                uses.trait_predicates.push((index, trait_predicate));
+            },
+            PredicateKind::Clause(ty::Clause::TypeOutlives(_) | ty::Clause::RegionOutlives(_)) => {},
+            kind => uses.other_predicates.push(kind),
+        }
+    }
+    Some(uses)
+}
+
+/// Removes the trait predicates matching `remove` from the caller bounds of `param_env`, e.g. to
+/// check whether a trait is implemented without the bound being linted.
+pub fn param_env_without<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ParamEnv<'tcx>,
+    mut remove: impl FnMut(ty::TraitPredicate<'tcx>) -> bool,
+) -> ParamEnv<'tcx> {
+    let caller_bounds = param_env.caller_bounds().iter().filter(|predicate| {
+        !matches!(
+            predicate.kind().skip_binder(),
+            PredicateKind::Clause(ty::Clause::Trait(trait_predicate)) if remove(trait_predicate)
+        )
+    });
+    ParamEnv::new(
+        tcx.mk_predicates_from_iter(caller_bounds),
+        param_env.reveal(),
+        param_env.constness(),
+    )
+}
+
 /// Same as `implements_trait` but allows using a `ParamEnv` different from the lint context.
 pub fn implements_trait_with_env<'tcx>(
     tcx: TyCtxt<'tcx>,
@@ -0,0 +1,17 @@
+//@run-rustfix
+#![warn(clippy::inlinable_where_clause)]
+#![allow(unused)]
+
+fn iterator<T>(x: T)
+where
+    T: Iterator<Item = u8>,
+{
+}
+
+fn too_long<T>(x: T)
+where
+    T: Iterator<Item = (u8, u16, u32, u64)>,
+{
+}
+
+fn main() {}
@@ -16,7 +16,8 @@
 use syn::spanned::Spanned;
 use syn::token::Star;
 use syn::{
-    parse_quote, FnArg, ImplItem, ItemImpl, ItemTrait, Lifetime, Pat, PatIdent, PatType, Signature, TraitItem, Type,
+    parse_quote, FnArg, ImplItem, ItemFn, ItemImpl, ItemTrait, Lifetime, Pat, PatIdent, PatType, Signature, TraitItem,
+    Type,
 };
 
 #[proc_macro_attribute]
@@ -99,3 +100,22 @@ fn mut_receiver_of(sig: &mut Signature) -> Option<&mut FnArg> {
 
     TokenStream::from(quote!(#item))
 }
+
+#[proc_macro_attribute]
+pub fn add_clone_where_bounds(_args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut item = parse_macro_input!(input as ItemFn);
+    let params = item
+        .sig
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+
+    // The added predicates get the call site span, marking them as coming from expansion
+    let where_clause = item.sig.generics.make_where_clause();
+    for param in params {
+        where_clause.predicates.push(parse_quote!(#param: Clone + Clone));
+    }
+
+    TokenStream::from(quote!(#item))
+}
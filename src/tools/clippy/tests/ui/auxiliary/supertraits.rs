@@ -0,0 +1,3 @@
+pub trait Super {}
+
+pub trait Sub: Super {}
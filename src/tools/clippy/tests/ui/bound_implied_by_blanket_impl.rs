@@ -0,0 +1,44 @@
+//@run-rustfix
+#![warn(clippy::bound_implied_by_blanket_impl)]
+#![allow(unused)]
+
+trait Marker {}
+impl<T> Marker for T {}
+
+trait UnsizedMarker {}
+impl<T: ?Sized> UnsizedMarker for T {}
+
+trait Conditional {}
+impl<T: Clone> Conditional for T {}
+
+fn inline<T: Marker>(x: T) {}
+
+fn where_clause<T>(x: T)
+where
+    T: Marker,
+{
+}
+
+fn with_other_bounds<T: Clone + Marker>(x: T) {}
+
+fn unsized_param<T: ?Sized + UnsizedMarker>(x: &T) {}
+
+// don't lint
+
+fn conditional<T: Conditional>(x: T) {}
+
+fn unsized_param_sized_impl<T: ?Sized + Marker>(x: &T) {}
+
+fn impl_trait(x: impl Marker) {}
+
+struct Wrapper<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized> Wrapper<T> {
+    fn unsized_impl_param(&self)
+    where
+        T: Marker,
+    {
+    }
+}
+
+fn main() {}
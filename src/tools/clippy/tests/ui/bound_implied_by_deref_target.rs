@@ -0,0 +1,74 @@
+#![warn(clippy::bound_implied_by_deref_target)]
+#![allow(unused)]
+
+use std::ops::Deref;
+
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+trait Consume {
+    fn consume(self) -> String;
+}
+
+impl Describe for String {
+    fn describe(&self) -> String {
+        self.clone()
+    }
+}
+
+fn inline_bound<T: Deref<Target = U> + Describe, U: Describe>(x: T) -> String {
+    x.describe()
+}
+
+fn where_clause<T, U>(x: &T) -> String
+where
+    T: Deref<Target = U> + Describe,
+    U: Describe,
+{
+    let describe = || x.describe();
+    describe() + &x.describe()
+}
+
+fn separate_bound<T: Describe, U: Describe>(x: T) -> String
+where
+    T: Deref<Target = U>,
+{
+    x.describe()
+}
+
+// Should not lint
+
+fn takes_describe<T: Describe>(x: &T) -> String {
+    x.describe()
+}
+
+fn passes_to_generic_fn<T: Deref<Target = U> + Describe, U: Describe>(x: T) -> String {
+    takes_describe(&x)
+}
+
+fn calls_by_path<T: Deref<Target = U> + Describe, U: Describe>(x: T) -> String {
+    Describe::describe(&x)
+}
+
+fn by_value_receiver<T: Deref<Target = U> + Consume, U: Consume>(x: T) -> String {
+    x.consume()
+}
+
+fn target_not_bound<T: Deref<Target = U> + Describe, U>(x: T) -> String {
+    x.describe()
+}
+
+fn concrete_target<T: Deref<Target = String> + Describe>(x: T) -> String {
+    x.describe()
+}
+
+fn other_bounds<T: Deref<Target = U> + Describe + Clone, U: Describe>(x: T) -> String {
+    x.clone().describe()
+}
+
+fn in_signature<T: Deref<Target = U> + Describe, U: Describe>(x: Vec<T>) -> String {
+    x[0].describe()
+}
+
+fn main() {}
@@ -0,0 +1,72 @@
+//@run-rustfix
+//@aux-build:supertraits.rs
+#![warn(clippy::bound_implied_by_supertrait)]
+#![allow(unused)]
+
+extern crate supertraits;
+
+use std::fmt::Debug;
+
+fn copy_clone<T: Copy + Clone>(x: T) {}
+
+fn ord_partial_ord<T: PartialOrd + Ord>(x: T) {}
+
+fn eq_partial_eq<T: Eq + PartialEq>(x: T) {}
+
+fn where_clause<T: Clone>(x: T)
+where
+    T: Copy,
+{
+}
+
+fn several<T: Ord + PartialOrd + Eq + Debug>(x: T) {}
+
+fn all_implied<T: Clone + PartialEq>(x: T)
+where
+    T: Copy + Eq,
+{
+}
+
+trait StaticTrait: 'static {}
+
+trait SubStaticTrait: StaticTrait {}
+
+fn implied_static<T: StaticTrait + 'static>(x: T) {}
+
+fn implied_static_where<T: SubStaticTrait>(x: T)
+where
+    T: 'static,
+{
+}
+
+trait Super {}
+
+trait Sub: Super {}
+
+impl<T: Super> Sub for T {}
+
+fn local_supertrait<T: Sub + Super>(x: T) {}
+
+trait SubSub: Sub {}
+
+fn transitive_supertrait<T: SubSub>(x: T)
+where
+    T: Super,
+{
+}
+
+fn extern_supertrait<T: supertraits::Sub + supertraits::Super>(x: T) {}
+
+// Should not lint
+
+fn with_args<T: PartialOrd<u32> + PartialEq>(x: T) {}
+
+fn other_param<T: Copy, U: Clone>(x: T, y: U) {}
+
+fn not_implied_static<T: Clone + 'static>(x: T) {}
+
+trait WithArgs: PartialEq<u32> {}
+
+fn supertrait_with_args<T: WithArgs + PartialEq>(x: T) {}
+
+fn main() {}
@@ -0,0 +1,22 @@
+#![warn(clippy::bound_on_const_param)]
+
+fn where_clause<const N: usize>()
+where
+    N: Clone,
+{
+}
+
+struct Array<T, const N: usize>([T; N])
+where
+    T: Copy,
+    N: Copy;
+
+// Should not lint
+
+fn type_param<T, const N: usize>()
+where
+    T: Clone,
+{
+}
+
+fn main() {}
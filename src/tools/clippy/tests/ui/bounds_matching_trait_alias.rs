@@ -0,0 +1,35 @@
+//@run-rustfix
+#![feature(trait_alias)]
+#![warn(clippy::bounds_matching_trait_alias)]
+#![allow(unused)]
+
+use std::fmt::Debug;
+
+trait CloneSend = Clone + Send;
+
+trait CloneSendSync = Clone + Send + Sync;
+
+fn inline<T: Clone + Send>(x: T) {}
+
+fn where_clause<T>(x: T)
+where
+    T: Send + Debug + Clone,
+{
+}
+
+fn largest_alias<T: Sync + Clone + Send>(x: T) {}
+
+struct Wrapper<T: Clone + Send>(T);
+
+// Should not lint
+
+fn partial<T: Clone + Debug>(x: T) {}
+
+fn alias_already_used<T: CloneSend>(x: T) {}
+
+mod other {
+    // the aliases are defined in another module
+    fn f<T: Clone + Send>(x: T) {}
+}
+
+fn main() {}
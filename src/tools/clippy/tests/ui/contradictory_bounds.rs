@@ -0,0 +1,37 @@
+#![warn(clippy::contradictory_bounds)]
+#![allow(drop_bounds, unused)]
+
+fn inline<T: Copy + Drop>(_: T) {}
+
+fn inline_drop_where_copy<T: Drop>(_: T)
+where
+    T: Copy,
+{
+}
+
+fn where_both<T>(_: T)
+where
+    T: Clone + Drop + Copy,
+{
+}
+
+struct Wrapper<T: Copy + Drop>(T);
+
+// Only one of the traits, should not lint
+fn copy<T: Copy>(_: T) {}
+
+fn drop<T: Clone + Drop>(_: T) {}
+
+// The traits on different parameters, should not lint
+fn different_params<T: Copy, U: Drop>(_: T, _: U) {}
+
+macro_rules! copy_drop_fn {
+    ($name:ident) => {
+        fn $name<T: Copy + Drop>(_: T) {}
+    };
+}
+
+// from a macro, should not lint
+copy_drop_fn!(in_macro);
+
+fn main() {}
@@ -0,0 +1,50 @@
+//@run-rustfix
+#![warn(clippy::contradictory_sized_bounds)]
+#![allow(unused, clippy::explicit_sized_bound)]
+
+fn inline<T: ?Sized + Sized>(_: &T) {}
+
+fn inline_maybe_where_sized<T: ?Sized>(_: &T)
+where
+    T: Sized,
+{
+}
+
+fn where_maybe_inline_sized<T: Sized>(_: &T)
+where
+    T: ?Sized,
+{
+}
+
+fn where_both<T: Sized, U>(_: &T, _: &U)
+where
+    T: Clone + ?Sized,
+    U: Sized + ?Sized + Copy,
+{
+}
+
+struct Wrapper<T: ?Sized + Sized>(T);
+
+// `?Sized` alone, should not lint
+fn maybe_sized<T: ?Sized>(_: &T) {}
+
+// `Sized` alone, should not lint
+fn sized<T: Sized>(_: &T) {}
+
+// `?Sized` and `Sized` on different parameters, should not lint
+fn different_params<T: ?Sized, U>(_: &T, _: &U)
+where
+    U: Sized,
+{
+}
+
+macro_rules! maybe_sized_fn {
+    ($name:ident) => {
+        fn $name<T: ?Sized + Sized>(_: &T) {}
+    };
+}
+
+// from a macro, should not lint
+maybe_sized_fn!(in_macro);
+
+fn main() {}
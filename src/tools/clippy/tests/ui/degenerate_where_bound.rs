@@ -0,0 +1,22 @@
+//@run-rustfix
+#![feature(never_type)]
+#![warn(clippy::degenerate_where_bound)]
+#![allow(unused)]
+
+fn unit() where (): Clone {}
+
+fn never() where !: Clone {}
+
+fn with_others<T>(x: T) where T: Clone, (): Copy {}
+
+struct S<T>(T) where (): Default;
+
+trait Trait where !: Copy {}
+
+// Don't lint
+
+fn unit_tuple<T>(x: T) where (T,): Clone {}
+
+fn empty_tuple_in_path<T>(x: T) where Option<()>: Clone {}
+
+fn main() {}
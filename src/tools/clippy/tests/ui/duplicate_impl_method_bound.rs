@@ -0,0 +1,99 @@
+//@run-rustfix
+#![warn(clippy::duplicate_impl_method_bound)]
+#![allow(unused)]
+
+struct Wrap<T>(T);
+
+impl<T: Clone> Wrap<T> {
+    fn whole(&self)
+    where
+        T: Clone,
+    {
+    }
+
+    fn partial(&self)
+    where
+        T: Clone + Default,
+    {
+    }
+}
+
+impl<T> Wrap<T>
+where
+    T: Copy,
+{
+    fn from_where(&self)
+    where
+        T: Copy,
+    {
+    }
+}
+
+// The impl is generated by the macro, the method is written by the user
+macro_rules! impl_with_clone {
+    ($ty:ident, $($method:item)*) => {
+        impl<T: Clone> $ty<T> {
+            $($method)*
+        }
+    };
+}
+
+struct Generated<T>(T);
+
+impl_with_clone!(Generated, fn generated_impl(&self) where T: Clone {});
+
+// The method is generated by the macro, the impl is written by the user
+macro_rules! clone_method {
+    ($name:ident) => {
+        fn $name(&self)
+        where
+            T: Clone,
+        {
+        }
+    };
+}
+
+struct Handwritten<T>(T);
+
+impl<T: Clone> Handwritten<T> {
+    clone_method!(generated_method);
+}
+
+// Both bounds come from the same tokens of the invocation
+macro_rules! impl_with_bound {
+    ($ty:ident, $bound:path) => {
+        impl<T: $bound> $ty<T> {
+            fn shared(&self)
+            where
+                T: $bound,
+            {
+            }
+        }
+    };
+}
+
+struct Shared<T>(T);
+
+impl_with_bound!(Shared, Default);
+
+// Should not lint
+
+struct Other<T>(T);
+
+impl<T: From<u8>> Other<T> {
+    fn other_args(&self)
+    where
+        T: From<u16>,
+    {
+    }
+
+    fn other_trait(&self)
+    where
+        T: Clone,
+    {
+    }
+
+    fn own_param<U: From<u8>>(&self, u: U) {}
+}
+
+fn main() {}
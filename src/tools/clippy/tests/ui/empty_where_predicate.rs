@@ -0,0 +1,39 @@
+//@run-rustfix
+#![warn(clippy::empty_where_predicate)]
+#![allow(unused)]
+
+fn only_predicate<T>(x: T)
+where
+    T:,
+{
+}
+
+fn with_other_predicates<T, U>(x: T, y: U)
+where
+    T:,
+    U: Clone,
+{
+}
+
+fn last_predicate<T, U>(x: T, y: U)
+where
+    U: Clone,
+    T:,
+{
+}
+
+// Should not lint
+
+fn with_bounds<T>(x: T)
+where
+    T: Clone,
+{
+}
+
+fn lifetime_predicate<'a, 'b>(x: &'a u8, y: &'b u8)
+where
+    'a:,
+{
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+//@run-rustfix
+#![warn(clippy::explicit_sized_bound)]
+#![allow(unused)]
+
+struct Struct<T: Sized> {
+    x: T,
+}
+
+enum Enum<T: Sized + Clone> {
+    A(T),
+}
+
+union Union<T: Copy + Sized> {
+    x: T,
+}
+
+struct Tuple<T>(T)
+where
+    T: Sized + Default;
+
+struct Twice<T: Sized + Clone + Sized> {
+    x: T,
+}
+
+// Should not lint
+
+struct Unsized<T: ?Sized> {
+    x: Box<T>,
+}
+
+// Also bound by `?Sized`, left to `contradictory_sized_bounds`
+struct MaybeSized<T: ?Sized + Sized> {
+    x: Box<T>,
+}
+
+struct MaybeSizedWhere<T: ?Sized>(Box<T>)
+where
+    T: Sized;
+
+fn function<T: Sized>(x: T) {}
+
+fn main() {}
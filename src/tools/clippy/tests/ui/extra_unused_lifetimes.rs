@@ -5,7 +5,8 @@
     dead_code,
     clippy::needless_lifetimes,
     clippy::needless_pass_by_value,
-    clippy::needless_arbitrary_self_type
+    clippy::needless_arbitrary_self_type,
+    clippy::unused_bounded_lifetimes
 )]
 #![warn(clippy::extra_unused_lifetimes)]
 
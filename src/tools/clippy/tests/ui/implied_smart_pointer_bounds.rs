@@ -0,0 +1,67 @@
+//@run-rustfix
+#![warn(clippy::implied_smart_pointer_bounds)]
+#![allow(unused)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+fn boxed<T>(_: T)
+where
+    T: Send,
+    Box<T>: Send,
+{
+}
+
+fn arc<T>(_: T)
+where
+    T: Send + Sync,
+    Arc<T>: Send + Sync,
+{
+}
+
+fn some_bounds<T>(_: T)
+where
+    T: Clone,
+    Box<T>: Clone + Default,
+{
+}
+
+// Should not lint
+
+// `Rc` is never `Send`
+fn rc<T>(_: T)
+where
+    T: Send,
+    Rc<T>: Send,
+{
+}
+
+// `Arc<T>` is only `Send` if `T` is `Sync` as well
+fn arc_without_sync<T>(_: T)
+where
+    T: Send,
+    Arc<T>: Send,
+{
+}
+
+fn without_pointee_bound<T>(_: T)
+where
+    Rc<T>: Clone,
+{
+}
+
+fn other_wrapper<T>(_: T)
+where
+    T: Send,
+    Vec<T>: Send,
+{
+}
+
+fn defaulted_trait_params<T>(_: T)
+where
+    T: PartialEq<u8>,
+    Box<T>: PartialEq,
+{
+}
+
+fn main() {}
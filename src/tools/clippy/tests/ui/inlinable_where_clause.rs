@@ -0,0 +1,79 @@
+//@run-rustfix
+#![warn(clippy::inlinable_where_clause)]
+#![allow(unused)]
+
+fn multiline<T>(x: T)
+where
+    T: Clone,
+{
+}
+
+fn one_line<T>(x: T) where T: Clone {}
+
+fn with_return<T>(x: T) -> T
+where
+    T: Clone + Send,
+{
+    x
+}
+
+trait Trait {
+    fn declared<T>(x: T)
+    where
+        T: Copy;
+}
+
+struct S;
+
+impl S {
+    fn method<T>(x: T)
+    where
+        T: Default,
+    {
+    }
+}
+
+// Don't lint
+
+fn inline_bound<T: Clone>(x: T)
+where
+    T: Send,
+{
+}
+
+fn long_predicate<T>(x: T)
+where
+    T: Iterator<Item = u8>,
+{
+}
+
+fn two_predicates<T, U>(x: T, y: U)
+where
+    T: Clone,
+    U: Clone,
+{
+}
+
+fn not_a_param<T>(x: T)
+where
+    Vec<T>: Clone,
+{
+}
+
+fn higher_ranked<T>(x: T)
+where
+    for<'a> T: Fn(&'a u8),
+{
+}
+
+struct Wrapper<T>(T);
+
+impl<T> Wrapper<T> {
+    fn outer_param()
+    where
+        T: Clone,
+    {
+    }
+}
+
+fn main() {}
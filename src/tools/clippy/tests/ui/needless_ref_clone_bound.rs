@@ -0,0 +1,34 @@
+//@run-rustfix
+#![warn(clippy::needless_ref_clone_bound)]
+#![allow(unused)]
+
+use std::fmt::Debug;
+
+fn inline_clone<'a, T: Clone>(x: &'a T)
+where
+    &'a T: Clone,
+{
+}
+
+fn where_clone<'a, T>(x: &'a T)
+where
+    T: Clone,
+    &'a T: Clone + Debug,
+{
+}
+
+// Should not lint
+
+fn reference_alone<'a, T>(x: &'a T)
+where
+    &'a T: Clone,
+{
+}
+
+fn other_trait<'a, T: Clone>(x: &'a T)
+where
+    &'a T: Debug,
+{
+}
+
+fn main() {}
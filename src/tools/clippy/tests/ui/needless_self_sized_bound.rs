@@ -0,0 +1,57 @@
+#![warn(clippy::needless_self_sized_bound)]
+#![allow(dead_code)]
+
+struct Foo<T>(T);
+
+impl<T> Foo<T> {
+    fn into_inner(self) -> T
+    where
+        Self: Sized,
+    {
+        self.0
+    }
+
+    fn with_other_bounds(&self)
+    where
+        Self: Sized + Clone,
+    {
+    }
+
+    // no `Self` bound, should not lint
+    fn unrelated(&self)
+    where
+        T: Sized,
+    {
+    }
+}
+
+struct Unsized<T: ?Sized>(T);
+
+impl<T: ?Sized> Unsized<T> {
+    // `Self` may be unsized here, so the bound is meaningful
+    fn by_value(self) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+}
+
+trait Trait {
+    // trait methods are not linted
+    fn by_value(self)
+    where
+        Self: Sized,
+    {
+    }
+}
+
+impl<T> Trait for Foo<T> {
+    fn by_value(self)
+    where
+        Self: Sized,
+    {
+    }
+}
+
+fn main() {}
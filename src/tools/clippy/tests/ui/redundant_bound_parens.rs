@@ -0,0 +1,29 @@
+//@run-rustfix
+#![warn(clippy::redundant_bound_parens)]
+#![allow(unused)]
+
+fn inline<T: (Clone)>(x: T) {}
+
+fn where_clause<T>(x: T)
+where
+    T: (Fn(u8)),
+{
+}
+
+fn maybe_sized<T: (?Sized) + Send>(x: &T) {}
+
+fn dyn_bound(x: &dyn (Send)) {}
+
+// Should not lint
+
+fn fn_trait<T: Fn(u8) -> u8>(x: T) {}
+
+fn fn_trait_where<T>(x: T)
+where
+    T: FnOnce(u8, u16) -> (u8, u16),
+{
+}
+
+fn hrtb<T: (for<'a> Fn(&'a u8))>(x: T) {}
+
+fn main() {}
@@ -0,0 +1,85 @@
+//@run-rustfix
+#![warn(clippy::redundant_concrete_self_bound)]
+#![allow(unused)]
+
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+#[derive(Clone, PartialEq)]
+struct Meters(u32);
+
+impl Describe for Meters {
+    fn describe(&self) -> String {
+        format!("{}m", self.0)
+    }
+}
+
+impl Meters {
+    fn written_type<T>(&self, _: T) -> Self
+    where
+        Meters: Clone,
+    {
+        self.clone()
+    }
+
+    fn self_path<T>(&self, _: T)
+    where
+        Self: Clone + Describe,
+    {
+    }
+
+    fn with_other_predicates<T>(&self, _: T)
+    where
+        Self: Clone,
+        T: Describe,
+    {
+    }
+}
+
+trait Convert {
+    fn convert<T: Describe>(&self, value: T) -> String;
+}
+
+impl Convert for Meters {
+    fn convert<T: Describe>(&self, value: T) -> String
+    where
+        Meters: Describe,
+    {
+        value.describe() + &self.describe()
+    }
+}
+
+// Should not lint
+
+struct Wrap<T>(T);
+
+impl<T> Wrap<T> {
+    fn generic_self(&self)
+    where
+        Self: Clone,
+    {
+    }
+}
+
+impl Meters {
+    fn generic_trait_args<T>(&self, _: T)
+    where
+        Meters: From<T>,
+    {
+    }
+
+    fn sized(self)
+    where
+        Self: Sized,
+    {
+    }
+
+    fn defaulted_trait_params(&self)
+    where
+        Meters: PartialEq,
+    {
+    }
+}
+
+fn main() {}
@@ -0,0 +1,65 @@
+//@run-rustfix
+#![warn(clippy::redundant_impl_supertrait_bound)]
+#![allow(unused)]
+
+trait Super {}
+trait Other {}
+trait Sub: Super {}
+trait SubOther: Super + Other {}
+
+struct Wrap<T>(T);
+
+impl<T> Super for Wrap<T> {}
+impl<T> Other for Wrap<T> {}
+
+impl<T> Sub for Wrap<T>
+where
+    Wrap<T>: Super,
+{
+}
+
+impl<T> SubOther for Wrap<T>
+where
+    Wrap<T>: Super + Other,
+{
+}
+
+// Should not lint
+
+struct Cond<T>(T);
+
+impl<T: Clone> Super for Cond<T> {}
+
+impl<T> Sub for Cond<T>
+where
+    Cond<T>: Super,
+{
+}
+
+struct Param<T>(T);
+
+impl<T> Super for Param<T> {}
+
+impl<T> Sub for Param<T>
+where
+    T: Super,
+{
+}
+
+trait SubEq: PartialEq {}
+
+struct Same<T>(T);
+
+impl<T> PartialEq for Same<T> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> SubEq for Same<T>
+where
+    Same<T>: PartialEq,
+{
+}
+
+fn main() {}
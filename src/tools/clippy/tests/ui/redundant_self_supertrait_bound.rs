@@ -0,0 +1,89 @@
+//@run-rustfix
+#![warn(clippy::redundant_self_supertrait_bound)]
+#![allow(unused)]
+
+trait Named: Clone {}
+trait Labeled: Default {}
+
+trait Tagged: Named {
+    fn tag(&self)
+    where
+        Self: Clone;
+}
+
+trait Both: Named + Labeled {
+    fn both(&self)
+    where
+        Self: Clone + Default;
+
+    fn with_copy(&self)
+    where
+        Self: Clone + Copy;
+}
+
+trait Deep: Tagged {
+    fn deep(&self)
+    where
+        Self: Named + Clone;
+}
+
+trait Declared: Named {
+    fn declared(&self)
+    where
+        Self: Named;
+}
+
+trait DeclaredAndInherited: Named + Labeled {
+    fn declared_and_inherited(&self)
+    where
+        Self: Copy + Labeled + Clone;
+}
+
+// Declared supertraits are left to `trait_duplication_in_bounds` when it's enabled
+#[deny(clippy::trait_duplication_in_bounds)]
+trait DeclaredDuplicate: Named {
+    fn declared_duplicate(&self)
+    where
+        Self: Named;
+}
+
+trait Cmp: PartialEq {}
+
+// Only `PartialEq<u8>` is declared, `PartialEq<Self>` is inherited through `Cmp`
+#[deny(clippy::trait_duplication_in_bounds)]
+trait DeclaredOtherArgs: PartialEq<u8> + Cmp {
+    fn declared_other_args(&self)
+    where
+        Self: PartialEq;
+}
+
+// Should not lint
+
+trait NotSuper: Named {
+    fn not_super(&self)
+    where
+        Self: Default;
+}
+
+trait Generic: AsRef<str> {}
+
+trait GenericSub: Generic {
+    fn generic(&self)
+    where
+        Self: AsRef<str>;
+}
+
+trait OnParam: Named {
+    fn on_param<T>(&self)
+    where
+        T: Clone;
+}
+
+// The supertrait is `PartialEq<u8>`, the bound is `PartialEq<Self>`
+trait ComparesU8: PartialEq<u8> {
+    fn compares_self(&self)
+    where
+        Self: PartialEq;
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+#![warn(clippy::repeated_inherent_impl_bounds)]
+#![allow(unused)]
+
+struct Foo<T>(T);
+
+impl<T: Clone> Foo<T> {
+    fn one(&self) {}
+}
+
+impl<T: Clone> Foo<T> {
+    fn two(&self) {}
+}
+
+impl<T> Foo<T>
+where
+    T: Clone,
+{
+    fn three(&self) {}
+}
+
+// Should not lint
+
+impl<T: Clone + Default> Foo<T> {
+    fn four(&self) {}
+}
+
+impl<T: Clone> Foo<Option<T>> {
+    fn five(&self) {}
+}
+
+impl<T> Foo<T> {
+    fn six(&self) {}
+}
+
+impl<T> Foo<T> {
+    fn seven(&self) {}
+}
+
+#[allow(clippy::repeated_inherent_impl_bounds)]
+impl<T: Clone> Foo<T> {
+    fn eight(&self) {}
+}
+
+fn main() {}
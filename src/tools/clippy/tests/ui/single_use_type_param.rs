@@ -0,0 +1,75 @@
+//@run-rustfix
+#![warn(clippy::single_use_type_param)]
+#![allow(unused)]
+
+use std::fmt::Display;
+
+fn single<T: Display>(x: T) {
+    println!("{x}");
+}
+
+fn multiple_bounds<T: Display + Clone>(x: &T) {
+    println!("{}", x.clone());
+}
+
+fn all_params<T: Display, U: Clone>(x: T, y: U, z: u32) {}
+
+fn some_params<T: Display, U: Clone>(x: T, y: U) -> U {
+    y
+}
+
+fn where_clause<T>(x: T)
+where
+    T: Display,
+{
+}
+
+struct S;
+
+impl S {
+    fn method<T: Display>(&self, x: T) {}
+}
+
+// don't lint when the parameter is used anywhere else
+
+fn used_in_return<T: Clone>(x: T) -> T {
+    x
+}
+
+fn used_twice<T: Display>(x: T, y: T) {}
+
+fn used_in_body<T: Default>(x: T) {
+    let _ = T::default();
+}
+
+fn used_in_own_bound<T: PartialEq<T>>(x: T) {}
+
+fn used_in_other_bound<T: Clone, U: From<T>>(x: T, y: U) -> U {
+    y
+}
+
+fn no_trait_bound<T>(x: T) {}
+
+pub fn exported<T: Display>(x: T) {}
+
+trait Trait {
+    fn trait_method<T: Display>(x: T);
+}
+
+impl Trait for S {
+    fn trait_method<T: Display>(x: T) {}
+}
+
+// Removing the parameter would break the call sites naming it with a turbofish
+fn called_with_turbofish<T: Display>(x: T) {}
+
+impl S {
+    fn method_called_with_turbofish<T: Display>(&self, x: T) {}
+}
+
+fn turbofish_callers() {
+    called_with_turbofish::<u8>(1);
+    S.method_called_with_turbofish::<u8>(1);
+}
+
+fn main() {}
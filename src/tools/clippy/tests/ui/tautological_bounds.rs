@@ -0,0 +1,50 @@
+//@run-rustfix
+#![warn(clippy::tautological_bounds)]
+#![allow(unused)]
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+fn object<T: AsRef<[Box<dyn Shape>]>>(shapes: T) -> f64
+where
+    dyn Shape: Shape,
+{
+    shapes.as_ref().iter().map(|shape| shape.area()).sum()
+}
+
+fn primitive<T>(_: T)
+where
+    i32: Copy + Default,
+    T: Clone,
+{
+}
+
+fn some_bounds<T>(_: T)
+where
+    T: Clone,
+    String: Clone + From<T>,
+{
+}
+
+// Should not lint
+
+fn generic<T>(_: T)
+where
+    Vec<T>: Clone,
+{
+}
+
+fn trait_args<T>(_: T)
+where
+    String: From<T>,
+{
+}
+
+fn defaulted_trait_params<T>(_: T)
+where
+    i32: PartialEq,
+{
+}
+
+fn main() {}
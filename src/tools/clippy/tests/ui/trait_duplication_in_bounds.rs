@@ -1,8 +1,10 @@
 //@run-rustfix
+//@aux-build:proc_macro_attr.rs
 #![deny(clippy::trait_duplication_in_bounds)]
 #![allow(unused)]
 
 use std::any::Any;
+use std::fmt::Debug;
 
 fn bad_foo<T: Clone + Clone + Clone + Copy, U: Clone + Copy>(arg0: T, argo1: U) {
     unimplemented!();
@@ -119,4 +121,165 @@ fn bad_trait_object(arg0: &(dyn Any + Send + Send)) {
     unimplemented!();
 }
 
+fn bad_restated_where_clause<T: Clone + Copy, U: Default>(arg0: T, arg1: U)
+where
+    T: Clone + Copy,
+    U: Default,
+{
+    unimplemented!();
+}
+
+#[macro_use]
+extern crate proc_macro_attr;
+
+// the where clause comes from the attribute macro, so neither it nor the inline bound should be linted
+#[add_clone_where_bounds]
+fn attr_macro_where_bounds<T: Clone>(arg0: T) {
+    unimplemented!();
+}
+
+macro_rules! fn_with_bounds {
+    ($name:ident, $($item:tt)*) => {
+        fn $name<T>(arg0: T) where T: Clone + Clone {}
+        $($item)*
+    };
+}
+
+// only the bounds written outside of the macro should be linted
+fn_with_bounds!(macro_where_bounds, fn user_bounds_in_macro<T: Clone + Clone>(arg0: T) {});
+
+// bounds in impl headers are checked as well
+trait ImplHeader {}
+
+impl<T: Clone + Clone> ImplHeader for T {}
+
+trait ImplHeaderWhereClause {}
+
+impl<T> ImplHeaderWhereClause for T
+where
+    T: Copy + Copy,
+{
+}
+
+// bounds on trait object types that are already part of the object type
+fn trait_object_predicate(arg0: Box<dyn Any + Send>)
+where
+    dyn Any + Send: Send,
+{
+}
+
+fn trait_object_predicate_partly(arg0: Box<dyn Any + Send>)
+where
+    dyn Any + Send: Send + Debug,
+{
+}
+
+// the trait object type of the argument is linted separately from the predicate
+fn trait_object_predicate_and_ty(arg0: &(dyn Any + Send + Send))
+where
+    dyn Any + Send + Send: Send,
+{
+}
+
+// bounds on type parameters used by a return-position `impl Trait` are removed from the where clause
+fn rpit_restated<T: Clone>(arg0: T) -> impl Iterator<Item = T>
+where
+    T: Clone,
+{
+    std::iter::once(arg0)
+}
+
+fn rpit_where_copy<T: Clone>(arg0: T) -> impl Iterator<Item = T>
+where
+    T: Clone + Debug,
+{
+    std::iter::once(arg0)
+}
+
+fn rpit_where_copies<T: Clone + Default>(arg0: T) -> impl Iterator<Item = T>
+where
+    T: Clone + Debug + Default,
+{
+    std::iter::once(arg0)
+}
+
+trait WithLifetime<'a> {}
+
+// bounds are only duplicates if their lifetime arguments are the same
+fn same_lifetime<'a, T: WithLifetime<'a> + WithLifetime<'a>>(arg0: T) {}
+
+fn same_lifetime_where<'a, T>(arg0: T)
+where
+    T: WithLifetime<'a> + WithLifetime<'a>,
+{
+}
+
+fn different_lifetimes<'a, 'b, T: WithLifetime<'a> + WithLifetime<'b>>(arg0: T) {}
+
+fn different_lifetimes_where<'a, 'b, T>(arg0: T)
+where
+    T: WithLifetime<'a> + WithLifetime<'b>,
+{
+}
+
+fn different_lifetimes_inline_and_where<'a, 'b, T: WithLifetime<'a>>(arg0: T)
+where
+    T: WithLifetime<'b>,
+{
+}
+
+// outlives bounds are deduplicated as well
+fn duplicate_outlives<'a, T: 'a + 'a>(arg0: &'a T) {}
+
+fn duplicate_outlives_mixed<'a, T: 'a + Clone + 'a>(arg0: &'a T) {}
+
+fn different_outlives<'a, 'b, T: 'a + 'b>(arg0: &'a T, arg1: &'b T) {}
+
+// bounds on type aliases are not enforced, and are not compared to the bounds of their uses
+#[allow(type_alias_bounds)]
+type Clonable<T: Clone> = T;
+
+fn alias_bound_and_inline<U: Clone>(arg0: Clonable<U>) {}
+
+fn alias_bound_and_where<U>(arg0: Clonable<U>)
+where
+    U: Clone,
+{
+}
+
+fn alias_in_where<U: Clone>(arg0: U)
+where
+    Clonable<U>: Clone,
+{
+}
+
+fn alias_of_different_params_in_where<T, U>(arg0: T, arg1: U)
+where
+    Clonable<T>: Clone,
+    Clonable<U>: Clone,
+{
+}
+
+// `Sized` is implied for type parameters, so repeating it needs none of the bounds
+fn sized_twice<T: Sized + Sized>(arg0: T) {}
+
+fn sized_twice_with_clone<T: Clone + Sized + Sized>(arg0: T) {}
+
+fn sized_twice_in_where<T>(arg0: T)
+where
+    T: Sized + Sized + Copy,
+{
+}
+
+// The parameters of the impl can be `?Sized`, so the `Sized` bound of the method is needed
+struct MaybeSizedImpl<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized> MaybeSizedImpl<T> {
+    fn sized_twice_in_method(&self)
+    where
+        T: Sized + Sized + Clone,
+    {
+    }
+}
+
 fn main() {}
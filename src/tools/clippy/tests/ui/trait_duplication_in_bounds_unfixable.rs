@@ -163,4 +163,90 @@ pub trait ImplTrait<T> {}
 
 impl<A, B> ImplTrait<(A, B)> for Foo where Foo: ImplTrait<A> + ImplTrait<B> {}
 
+// bounds on the generic parameters of the trait
+trait TraitParam<X: Clone> {
+    fn duplicated<Y>()
+    where
+        X: Clone;
+
+    fn different_bound<Y>()
+    where
+        X: Copy;
+}
+
+trait TraitParamWhere<X>
+where
+    X: Default,
+{
+    fn duplicated(x: X)
+    where
+        X: Default + Clone;
+}
+
+// bounds on `Self` in the where clause of the trait
+trait SelfWhere
+where
+    Self: Clone,
+{
+    type Duplicated
+    where
+        Self: Clone;
+
+    type Different
+    where
+        Self: Default;
+}
+
+trait Container {
+    type Item;
+}
+
+// bounds on a qualified path are on the associated type, not on the parameter
+fn qualified_where<T: Container + Clone>(x: T)
+where
+    T: Clone,
+    <T as Container>::Item: Clone,
+{
+    unimplemented!();
+}
+
+fn qualified_where_only<T: Container + Clone>(x: T)
+where
+    <T as Container>::Item: Clone,
+{
+    unimplemented!();
+}
+
+fn qualified_where_of_params<T: Container, U: Container>(x: T, y: U)
+where
+    <T as Container>::Item: Clone,
+    <U as Container>::Item: Clone,
+{
+    unimplemented!();
+}
+
+// bounds of an impl on an associated type of the trait, which its declaration already requires
+trait Buffer {
+    type Item: Clone + Default;
+}
+
+struct Bytes;
+
+impl Buffer for Bytes
+where
+    Self::Item: Clone,
+    <Bytes as Buffer>::Item: Default + Copy,
+{
+    type Item = u8;
+}
+
+struct Words<T>(T);
+
+impl<T: Clone + Default> Buffer for Words<T>
+where
+    Self::Item: Copy,
+{
+    type Item = T;
+}
+
 fn main() {}
@@ -92,6 +92,70 @@ pub fn g<T: Clone>()
 {
 }
 
+// Bounds present in both predicates are only listed once
+pub fn reordered<T>()
+where
+    T: Copy + Clone,
+    T: Clone + Copy,
+{
+}
+pub fn overlapping<T>()
+where
+    T: Clone + Copy,
+    T: Copy + Default,
+{
+}
+
+// A bound without associated type bindings is implied by the same trait with bindings
+pub fn binding_first<T>()
+where
+    T: Iterator<Item = u8>,
+    T: Iterator,
+{
+}
+pub fn binding_last<T>()
+where
+    T: Iterator,
+    T: Iterator<Item = u8> + Clone,
+{
+}
+
+// Outlives bounds are kept when the repeated trait is merged
+pub fn outlives_merged<'a, T>(_: &'a T)
+where
+    T: Clone,
+    T: Clone + 'a,
+{
+}
+pub fn outlives_repeated<'a, T>(_: &'a T)
+where
+    T: Clone + 'a,
+    T: Default + 'a,
+{
+}
+
+// Qualified and shorthand paths to the same associated type are the same type
+pub fn qualified_first<T: Iterator>()
+where
+    <T as Iterator>::Item: Clone,
+    T::Item: Default,
+{
+}
+pub fn shorthand_first<T: Iterator>()
+where
+    T::Item: Clone,
+    <T as Iterator>::Item: Clone,
+{
+}
+
+// This should not lint, the associated types of different parameters are different types
+pub fn different_projections<T: Iterator, U: Iterator>()
+where
+    T::Item: Clone,
+    U::Item: Clone,
+{
+}
+
 // This should not lint
 fn impl_trait(_: impl AsRef<str>, _: impl AsRef<str>) {}
 
@@ -0,0 +1,47 @@
+#![warn(clippy::unused_bounded_lifetimes)]
+#![allow(unused, clippy::extra_unused_lifetimes, clippy::needless_lifetimes)]
+
+fn both_unused<'a: 'b, 'b>() {}
+
+fn where_clause<'a, 'b>()
+where
+    'a: 'b,
+{
+}
+
+fn outlives_used<'a, 'b: 'a>(x: &'b u8) {}
+
+struct S;
+
+impl S {
+    fn method<'a: 'b, 'b>(&self) {}
+}
+
+trait Tr {
+    fn required<'a: 'b, 'b>();
+}
+
+// Should not lint
+fn used_in_signature<'a: 'b, 'b>(x: &'a u8, y: &'b u8) {}
+
+fn used_in_return<'a, 'b: 'a>(x: &'b u8) -> &'a u8 {
+    x
+}
+
+fn used_in_type_bound<'a: 'b, 'b, T: 'a>(x: &'b T) {}
+
+fn used_in_where_clause<'a: 'b, 'b, T>(x: &'b T)
+where
+    T: 'a,
+{
+}
+
+fn used_in_body<'a: 'b, 'b>(x: &'b u8) {
+    let _: Option<&'a u8> = None;
+}
+
+impl Tr for S {
+    fn required<'a: 'b, 'b>() {}
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+#![warn(clippy::unused_private_fn_bound)]
+#![allow(unused)]
+
+use std::fmt::Debug;
+
+fn ignored<T: Clone>(_value: T) {}
+
+fn where_clause<T>(_value: &T)
+where
+    T: Clone + Debug,
+{
+}
+
+fn with_marker<T: Clone + Send>(_value: T) {}
+
+struct S;
+
+impl S {
+    fn method<T: Default>(&self, _value: T) {}
+}
+
+mod private {
+    pub fn not_reexported<T: Clone>(_value: T) {}
+}
+
+// Should not lint
+
+pub fn public<T: Clone>(_value: T) {}
+
+pub struct P;
+
+impl P {
+    pub fn public_method<T: Clone>(&self, _value: T) {}
+}
+
+fn cloned<T: Clone>(value: &T) -> T {
+    value.clone()
+}
+
+fn passed_on<T: Clone>(value: T) {
+    ignored(value);
+}
+
+fn in_trait_argument<T: Clone, U: From<T>>(_value: T, other: U) -> U {
+    other
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+#![warn(clippy::unused_send_sync_bound)]
+#![allow(unused)]
+
+use std::thread;
+
+fn ignored<T: Send>(_value: T) {}
+
+fn where_clause<T>(_value: &T)
+where
+    T: Send + Sync,
+{
+}
+
+fn one_marker<T: Clone + Sync>(_value: T) -> u32 {
+    0
+}
+
+struct S;
+
+impl S {
+    fn method<T: Send>(&self, _value: T) {}
+}
+
+// Should not lint
+
+fn spawned<T: Send + 'static>(value: T) {
+    thread::spawn(move || drop(value));
+}
+
+fn returned<T: Send>(value: T) -> T {
+    value
+}
+
+fn in_generic_type<T: Send>(_value: Vec<T>) {}
+
+fn in_closure<T: Send>(_value: T) {
+    let f = || 0;
+}
+
+fn as_trait_argument<T: Send, U: From<T>>(_value: T) {}
+
+fn main() {}
@@ -0,0 +1,90 @@
+#![warn(clippy::unused_trait_bound)]
+#![allow(unused)]
+
+use std::fmt::Debug;
+
+trait Greet {
+    fn greet(&self) -> String {
+        String::from("hello")
+    }
+}
+
+fn identity<T: Greet>(x: T) -> T {
+    x
+}
+
+fn where_clause<T>(x: &T) -> &T
+where
+    T: Greet,
+{
+    x
+}
+
+fn one_unused<T: Greet + Clone>(x: &T) -> String {
+    x.greet()
+}
+
+struct S;
+
+impl S {
+    fn method<T: Clone>(&self, x: T) -> T {
+        x
+    }
+}
+
+// `x` is only ever borrowed, but `Clone` isn't needed for that
+fn only_borrows<T: Clone>(x: &T) -> &T {
+    let y = &x;
+    *y
+}
+
+// Should not lint
+
+fn calls_default_method<T: Greet>(x: &T) -> String {
+    x.greet()
+}
+
+fn calls_by_path<T: Greet>(x: &T) -> String {
+    Greet::greet(x)
+}
+
+fn clones<T: Clone>(x: &T) -> T {
+    x.clone()
+}
+
+fn calls_supertrait_method<T: Ord>(a: &T, b: &T) -> bool {
+    a == b
+}
+
+fn passes_to_generic_fn<T: Debug>(x: T) {
+    println!("{x:?}");
+}
+
+fn coerces_to_dyn<T: Debug>(x: &T) -> &dyn Debug {
+    x
+}
+
+fn returns_impl_trait<T: Clone>(x: T) -> impl Clone {
+    x
+}
+
+fn in_generic_type<T: Clone>(x: Vec<T>) -> Vec<T> {
+    x
+}
+
+fn marker_trait<T: Copy>(x: T) -> T {
+    let y = x;
+    x
+}
+
+trait WithMethod {
+    fn method<T: Clone>(&self, x: T) -> T;
+}
+
+impl WithMethod for S {
+    fn method<T: Clone>(&self, x: T) -> T {
+        x
+    }
+}
+
+fn main() {}
@@ -238,6 +238,40 @@ pub fn has_doc_hidden(&self) -> bool {
         })
     }
 
+    /// Returns the aliases declared through `#[doc(alias = "...")]` or `#[doc(alias("...", ...))]`.
+    pub fn doc_aliases(&self) -> Vec<SmolStr> {
+        let mut aliases = Vec::new();
+        for tt in self.by_key("doc").tt_values() {
+            let mut token_trees = tt.token_trees.iter().peekable();
+            while let Some(token_tree) = token_trees.next() {
+                match token_tree {
+                    tt::TokenTree::Leaf(tt::Leaf::Ident(ident)) if ident.text == "alias" => {}
+                    _ => continue,
+                }
+                match token_trees.peek() {
+                    Some(tt::TokenTree::Leaf(tt::Leaf::Punct(punct))) if punct.char == '=' => {
+                        token_trees.next();
+                        if let Some(tt::TokenTree::Leaf(tt::Leaf::Literal(lit))) = token_trees.next()
+                        {
+                            aliases.push(SmolStr::new(lit.text.trim_matches('"')));
+                        }
+                    }
+                    Some(tt::TokenTree::Subtree(subtree)) => {
+                        aliases.extend(subtree.token_trees.iter().filter_map(|it| match it {
+                            tt::TokenTree::Leaf(tt::Leaf::Literal(lit)) => {
+                                Some(SmolStr::new(lit.text.trim_matches('"')))
+                            }
+                            _ => None,
+                        }));
+                        token_trees.next();
+                    }
+                    _ => {}
+                }
+            }
+        }
+        aliases
+    }
+
     pub fn is_proc_macro(&self) -> bool {
         self.by_key("proc_macro").exists()
     }
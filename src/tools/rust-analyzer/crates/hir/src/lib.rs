@@ -45,10 +45,10 @@
     generics::{LifetimeParamData, TypeOrConstParamData, TypeParamProvenance},
     item_tree::ItemTreeNode,
     lang_item::{LangItem, LangItemTarget},
-    layout::{Layout, LayoutError, ReprOptions},
+    layout::{Layout, LayoutError, ReprOptions, RustcEnumVariantIdx, Variants},
     nameres::{self, diagnostics::DefDiagnostic, ModuleOrigin},
     per_ns::PerNs,
-    resolver::{HasResolver, Resolver},
+    resolver::{HasResolver, Resolver, TypeNs},
     src::HasSource as _,
     AdtId, AssocItemId, AssocItemLoc, AttrDefId, ConstId, ConstParamId, DefWithBodyId, EnumId,
     EnumVariantId, FunctionId, GenericDefId, HasModule, ImplId, ItemContainerId, LifetimeParamId,
@@ -914,6 +914,51 @@ pub fn ty(&self, db: &dyn HirDatabase) -> Type {
         Type::new(db, var_id, ty)
     }
 
+    /// Returns the type of the field with the type parameters of its parent
+    /// substituted by the given type arguments, see `Adt::ty_with_args`.
+    pub fn ty_with_args(&self, db: &dyn HirDatabase, args: &[Type]) -> Type {
+        let var_id = self.parent.into();
+        let generic_def_id: GenericDefId = match self.parent {
+            VariantDef::Struct(it) => it.id.into(),
+            VariantDef::Union(it) => it.id.into(),
+            VariantDef::Variant(it) => it.parent.id.into(),
+        };
+        let mut it = args.iter().map(|t| t.ty.clone());
+        let substs = TyBuilder::subst_for_def(db, generic_def_id, None)
+            .fill(|x| {
+                let r = it.next().unwrap_or_else(|| TyKind::Error.intern(Interner));
+                match x {
+                    ParamKind::Type => GenericArgData::Ty(r).intern(Interner),
+                    ParamKind::Const(ty) => unknown_const_as_generic(ty.clone()),
+                }
+            })
+            .build();
+        let ty = db.field_types(var_id)[self.id].clone().substitute(Interner, &substs);
+        Type::new(db, var_id, ty)
+    }
+
+    /// Returns the type alias the field's type is written with, if it is written as a path to one
+    /// without generic arguments, like `Id` in `id: Id`.
+    pub fn type_alias(&self, db: &dyn HirDatabase) -> Option<TypeAlias> {
+        let var_id: hir_def::VariantId = self.parent.into();
+        let variant_data = self.parent.variant_data(db);
+        let TypeRef::Path(path) = &*variant_data.fields()[self.id].type_ref else {
+            return None;
+        };
+        if path.type_anchor().is_some()
+            || path.segments().iter().any(|segment| segment.args_and_bindings.is_some())
+        {
+            return None;
+        }
+        match var_id
+            .resolver(db.upcast())
+            .resolve_path_in_type_ns_fully(db.upcast(), path.mod_path())?
+        {
+            TypeNs::TypeAliasId(id) => Some(TypeAlias { id }),
+            _ => None,
+        }
+    }
+
     pub fn layout(&self, db: &dyn HirDatabase) -> Result<Layout, LayoutError> {
         layout_of_ty(db, &self.ty(db).ty, self.parent.module(db).krate().into())
     }
@@ -1107,6 +1152,11 @@ pub fn name(self, db: &dyn HirDatabase) -> Name {
         db.enum_data(self.parent.id).variants[self.id].name.clone()
     }
 
+    /// Returns the index of the variant in the declaration order of its enum.
+    pub fn index(self) -> usize {
+        u32::from(self.id.into_raw()) as usize
+    }
+
     pub fn fields(self, db: &dyn HirDatabase) -> Vec<Field> {
         self.variant_data(db)
             .fields()
@@ -1130,6 +1180,17 @@ pub fn value(self, db: &dyn HirDatabase) -> Option<ast::Expr> {
     pub fn eval(self, db: &dyn HirDatabase) -> Result<i128, ConstEvalError> {
         db.const_eval_discriminant(self.into())
     }
+
+    /// Returns the layout of the variant in its enum, which includes the tag, if any.
+    pub fn layout(self, db: &dyn HirDatabase) -> Result<Layout, LayoutError> {
+        let layout = Adt::from(self.parent).layout(db)?;
+        match layout.variants {
+            Variants::Multiple { variants, .. } => {
+                Ok(variants[RustcEnumVariantIdx(self.id)].clone())
+            }
+            Variants::Single { .. } => Ok(layout),
+        }
+    }
 }
 
 /// Variants inherit visibility from the parent enum.
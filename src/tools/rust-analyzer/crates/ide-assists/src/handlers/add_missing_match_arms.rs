@@ -1,9 +1,12 @@
 use std::iter::{self, Peekable};
 
 use either::Either;
-use hir::{Adt, Crate, HasAttrs, HasSource, ModuleDef, Semantics};
+use hir::{Adt, Crate, HasAttrs, ModuleDef, Semantics};
 use ide_db::RootDatabase;
-use ide_db::{famous_defs::FamousDefs, helpers::mod_path_to_ast};
+use ide_db::{
+    famous_defs::FamousDefs,
+    helpers::{mod_path_to_ast, variant_pat},
+};
 use itertools::Itertools;
 use syntax::ast::edit_in_place::Removable;
 use syntax::ast::{self, make, AstNode, HasName, MatchArmList, MatchExpr, Pat};
@@ -404,23 +407,7 @@ fn build_pat(
         ExtendedVariant::Variant(var) => {
             let path =
                 mod_path_to_ast(&module.find_use_path(db, ModuleDef::from(var), prefer_no_std)?);
-
-            // FIXME: use HIR for this; it doesn't currently expose struct vs. tuple vs. unit variants though
-            let pat: ast::Pat = match var.source(db)?.value.kind() {
-                ast::StructKind::Tuple(field_list) => {
-                    let pats =
-                        iter::repeat(make::wildcard_pat().into()).take(field_list.fields().count());
-                    make::tuple_struct_pat(path, pats).into()
-                }
-                ast::StructKind::Record(field_list) => {
-                    let pats = field_list
-                        .fields()
-                        .map(|f| make::ext::simple_ident_pat(f.name().unwrap()).into());
-                    make::record_pat(path, pats).into()
-                }
-                ast::StructKind::Unit => make::path_pat(path),
-            };
-            Some(pat)
+            variant_pat(db, path, var)
         }
         ExtendedVariant::True => Some(ast::Pat::from(make::literal_pat("true"))),
         ExtendedVariant::False => Some(ast::Pat::from(make::literal_pat("false"))),
@@ -773,6 +760,35 @@ fn main() {
         );
     }
 
+    #[test]
+    fn patterns_match_missing_variants_completion() {
+        // Keep in sync with `missing_variants_pats_match_add_missing_match_arms` in the completion
+        // tests, both build their patterns with `ide_db::helpers::variant_pat`
+        check_assist(
+            add_missing_match_arms,
+            r#"
+enum E { A, B(u8, u16), C }
+
+fn foo(e: E) {
+    match e$0 {
+        E::A => (),
+    }
+}
+"#,
+            r#"
+enum E { A, B(u8, u16), C }
+
+fn foo(e: E) {
+    match e {
+        E::A => (),
+        $0E::B(_, _) => todo!(),
+        E::C => todo!(),
+    }
+}
+"#,
+        );
+    }
+
     #[test]
     fn partial_fill_option() {
         check_assist(
@@ -38,7 +38,7 @@
         function::{render_fn, render_method},
         literal::{render_struct_literal, render_variant_lit},
         macro_::render_macro,
-        pattern::{render_struct_pat, render_variant_pat},
+        pattern::{render_missing_variants_pat, render_struct_pat, render_variant_pat},
         render_field, render_path_resolution, render_pattern_resolution, render_tuple_field,
         type_alias::{render_type_alias, render_type_alias_with_eq},
         union_literal::render_union_literal,
@@ -78,6 +78,35 @@ fn add_opt(&mut self, item: Option<CompletionItem>) {
         }
     }
 
+    /// Appends the name of the defining crate to the detail of the enum variants from other
+    /// crates, if another variant with the same name but from a different crate was completed as
+    /// well.
+    pub(crate) fn add_variant_crate_names(&mut self, ctx: &CompletionContext<'_>) {
+        let variants = self
+            .buf
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, item)| {
+                let (name, krate) = item.variant_origin.as_ref()?;
+                Some((idx, name.clone(), *krate))
+            })
+            .collect::<Vec<_>>();
+        for (idx, name, krate) in &variants {
+            let collides = variants
+                .iter()
+                .any(|(_, other_name, other_krate)| other_name == name && other_krate != krate);
+            if !collides || *krate == ctx.krate {
+                continue;
+            }
+            let Some(crate_name) = krate.display_name(ctx.db) else { continue };
+            let item = &mut self.buf[*idx];
+            item.detail = Some(match item.detail.take() {
+                Some(detail) => format!("{detail} (from {crate_name})"),
+                None => format!("(from {crate_name})"),
+            });
+        }
+    }
+
     pub(crate) fn add_all<I>(&mut self, items: I)
     where
         I: IntoIterator,
@@ -388,7 +417,7 @@ pub(crate) fn add_qualified_enum_variant(
         if let Some(builder) =
             render_variant_lit(RenderContext::new(ctx), path_ctx, None, variant, Some(path))
         {
-            self.add(builder.build());
+            self.add_variant_item(ctx, variant, builder);
         }
     }
 
@@ -408,10 +437,23 @@ pub(crate) fn add_enum_variant(
         if let Some(builder) =
             render_variant_lit(RenderContext::new(ctx), path_ctx, local_name, variant, None)
         {
-            self.add(builder.build());
+            self.add_variant_item(ctx, variant, builder);
         }
     }
 
+    fn add_variant_item(
+        &mut self,
+        ctx: &CompletionContext<'_>,
+        variant: hir::Variant,
+        builder: Builder,
+    ) {
+        let mut item = builder.build();
+        if let Some(hook) = ctx.config.variant_item_hook {
+            hook(variant, &mut item);
+        }
+        self.add(item);
+    }
+
     pub(crate) fn add_field(
         &mut self,
         ctx: &CompletionContext<'_>,
@@ -517,6 +559,14 @@ pub(crate) fn add_qualified_variant_pat(
         ));
     }
 
+    pub(crate) fn add_missing_variants_pat(
+        &mut self,
+        ctx: &CompletionContext<'_>,
+        missing_variants: &[hir::Variant],
+    ) {
+        self.add_opt(render_missing_variants_pat(RenderContext::new(ctx), missing_variants));
+    }
+
     pub(crate) fn add_struct_pat(
         &mut self,
         ctx: &CompletionContext<'_>,
@@ -563,6 +613,7 @@ fn enum_variants_with_paths(
             // Variants with trivial paths are already added by the existing completion logic,
             // so we should avoid adding these twice
             if path.segments().len() > 1 {
+                let path = ctx.variant_use_path(hir::ModuleDef::from(variant)).unwrap_or(path);
                 cb(acc, ctx, variant, path);
             }
         }
@@ -12,7 +12,10 @@
         CompletionContext, DotAccess, PathCompletionCtx, PathKind, PatternContext, Qualified,
         TypeLocation,
     },
-    render::{render_resolution_with_import, render_resolution_with_import_pat, RenderContext},
+    render::{
+        literal::render_variant_lit_with_full_path, render_resolution_with_import,
+        render_resolution_with_import_pat, RenderContext,
+    },
 };
 
 use super::Completions;
@@ -276,8 +279,27 @@ fn import_on_the_fly(
                     &user_input_lowercased,
                 )
             })
-            .filter_map(|import| {
+            .flat_map(|import| {
+                // Variants can also be completed with their full path, as an alternative to
+                // importing them. Both items keep their own label, so that they are both listed.
+                let full_path = match import.original_item {
+                    ItemInNs::Types(ModuleDef::Variant(variant))
+                    | ItemInNs::Values(ModuleDef::Variant(variant))
+                        if ctx.config.variant_import_alternatives
+                            && !ctx.config.full_variant_paths
+                            && !ctx.config.no_import_edits =>
+                    {
+                        render_variant_lit_with_full_path(
+                            RenderContext::new(ctx),
+                            path_ctx,
+                            variant,
+                        )
+                    }
+                    _ => None,
+                };
                 render_resolution_with_import(RenderContext::new(ctx), path_ctx, import)
+                    .into_iter()
+                    .chain(full_path)
             })
             .map(|builder| builder.build()),
     );
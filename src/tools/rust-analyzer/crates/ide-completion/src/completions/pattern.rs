@@ -60,6 +60,9 @@ pub(crate) fn complete_pattern(
                 },
             );
         }
+        if refutable && pattern_ctx.parent_pat.is_none() {
+            acc.add_missing_variants_pat(ctx, &pattern_ctx.missing_variants);
+        }
     }
 
     // FIXME: ideally, we should look at the type we are matching against and
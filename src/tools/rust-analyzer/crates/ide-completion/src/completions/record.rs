@@ -1,6 +1,10 @@
 //! Complete fields in record literals and patterns.
 use ide_db::SymbolKind;
-use syntax::ast::{self, Expr};
+use itertools::Itertools;
+use syntax::{
+    ast::{self, Expr},
+    AstNode,
+};
 
 use crate::{
     context::{DotAccess, DotAccessKind, PatternContext},
@@ -72,6 +76,7 @@ pub(crate) fn complete_record_expr_fields(
                 item.add_to(acc);
                 return;
             }
+            add_remaining_fields(acc, ctx, record_expr, &missing_fields);
             missing_fields
         }
     };
@@ -102,6 +107,45 @@ pub(crate) fn add_default_update(
     }
 }
 
+/// Offers a single snippet that fills in all fields not yet written in a partially specified
+/// enum variant record literal, e.g. `E::V { a: 1, $0 }` completes `b: ${1:()}, c: ${2:()}`.
+fn add_remaining_fields(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    record_expr: &ast::RecordExpr,
+    missing_fields: &[(hir::Field, hir::Type)],
+) {
+    let cap = match ctx.config.snippet_cap {
+        Some(it) => it,
+        None => return,
+    };
+    let is_enum_variant = missing_fields.first().map_or(false, |(field, _)| {
+        matches!(field.parent_def(ctx.db), hir::VariantDef::Variant(_))
+    });
+    if !is_enum_variant {
+        return;
+    }
+    // The field under the cursor is the one being completed, don't count it as written.
+    let token_range = ctx.original_token.text_range();
+    let has_written_fields = record_expr.record_expr_field_list().map_or(false, |fl| {
+        fl.fields().any(|field| !field.syntax().text_range().contains_range(token_range))
+    });
+    if !has_written_fields {
+        return;
+    }
+
+    let label = missing_fields.iter().format_with(", ", |(field, _), f| {
+        f(&format_args!("{}: …", field.name(ctx.db).unescaped()))
+    });
+    let snippet = missing_fields.iter().enumerate().format_with(", ", |(idx, (field, _)), f| {
+        f(&format_args!("{}: ${{{}:()}}", field.name(ctx.db), idx + 1))
+    });
+    let mut item =
+        CompletionItem::new(CompletionItemKind::Snippet, ctx.source_range(), label.to_string());
+    item.insert_snippet(cap, snippet.to_string());
+    item.add_to(acc);
+}
+
 fn complete_fields(
     acc: &mut Completions,
     ctx: &CompletionContext<'_>,
@@ -156,6 +200,27 @@ fn baz() {
         )
     }
 
+    #[test]
+    fn fill_remaining_record_variant_fields() {
+        check_edit(
+            "b: …",
+            r#"
+enum E { V { a: u32, b: u32 } }
+
+fn f() -> E {
+    E::V { a: 1, $0 }
+}
+"#,
+            r#"
+enum E { V { a: u32, b: u32 } }
+
+fn f() -> E {
+    E::V { a: 1, b: ${1:()} }
+}
+"#,
+        );
+    }
+
     #[test]
     fn enum_variant_no_snippets() {
         let conf = CompletionConfig { snippet_cap: SnippetCap::new(false), ..TEST_CONFIG };
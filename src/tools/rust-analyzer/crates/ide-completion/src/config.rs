@@ -6,7 +6,7 @@
 
 use ide_db::{imports::insert_use::InsertUseConfig, SnippetCap};
 
-use crate::snippet::Snippet;
+use crate::{snippet::Snippet, CompletionItem};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CompletionConfig {
@@ -20,8 +20,35 @@ pub struct CompletionConfig {
     pub prefer_no_std: bool,
     pub snippets: Vec<Snippet>,
     pub limit: Option<usize>,
+    pub tuple_fields_limit: Option<usize>,
+    pub full_variant_paths: bool,
+    pub variant_doc_summary_limit: Option<usize>,
+    pub group_expected_variants: bool,
+    pub tuple_variant_fn_detail: bool,
+    pub add_call_parenthesis_for_variants: bool,
+    pub variant_crate_names: bool,
+    pub record_variant_brace_spaces: bool,
+    pub compact_variant_docs: bool,
+    pub todo_variant_fields: bool,
+    pub newtype_variant_type_placeholder: bool,
+    pub no_import_edits: bool,
+    pub variant_repr_detail: bool,
+    pub variant_paths_import_prefix: bool,
+    pub single_cursor_tuple_variants: bool,
+    pub variant_import_alternatives: bool,
+    pub expand_variant_field_aliases: bool,
+    pub variant_prefilter: bool,
+    pub option_fields_none: bool,
+    pub variant_payload_size: bool,
+    pub variant_record_field_names: bool,
+    pub resolve_variant_docs: bool,
+    /// Called on each built completion item of an enum variant literal, so that clients can attach
+    /// their own data to the item.
+    pub variant_item_hook: Option<VariantItemHook>,
 }
 
+pub type VariantItemHook = fn(hir::Variant, &mut CompletionItem);
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CallableSnippets {
     FillArguments,
@@ -15,6 +15,7 @@
     helpers::is_editable_crate,
     FxHashMap, FxHashSet, RootDatabase,
 };
+use once_cell::unsync::OnceCell;
 use syntax::{
     ast::{self, AttrKind, NameOrNameRef},
     AstNode,
@@ -148,6 +149,9 @@ pub(crate) struct ExprCtx {
     /// Whether this expression occurs in match arm guard position: before the
     /// fat arrow token
     pub(crate) in_match_guard: bool,
+    /// Whether this expression is returned by the innermost function, either as the tail
+    /// expression of its body or as the operand of a `return`
+    pub(crate) in_return_position: bool,
 }
 
 /// Original file ast nodes
@@ -377,6 +381,9 @@ pub(crate) struct CompletionContext<'a> {
     pub(super) qualifier_ctx: QualifierCtx,
 
     pub(super) locals: FxHashMap<Name, Local>,
+    /// The names of the functions and structs in scope, which enum variants completed by their
+    /// name alone would collide with. Collected on first use.
+    pub(super) fn_and_struct_names: OnceCell<FxHashSet<Name>>,
 
     /// The module depth of the current module of the cursor position.
     /// - crate-root
@@ -402,6 +409,40 @@ pub(crate) fn source_range(&self) -> TextRange {
         }
     }
 
+    /// The part of the identifier being completed that is before the cursor.
+    pub(crate) fn typed_prefix(&self) -> &str {
+        if self.original_token.kind() != IDENT {
+            return "";
+        }
+        let start = self.original_token.text_range().start();
+        &self.original_token.text()[..usize::from(self.position.offset - start)]
+    }
+
+    /// Whether the token under the cursor comes from the expansion of an attribute or derive
+    /// macro, which is handed the whole item as input. The tokens of function-like macro calls map
+    /// back to the original file, so they are not counted.
+    pub(crate) fn is_in_macro_expansion(&self) -> bool {
+        self.token.parent().map_or(false, |it| {
+            let file = self.sema.hir_file_for(&it);
+            file.is_attr_macro(self.db) || file.is_custom_derive(self.db)
+        })
+    }
+
+    /// Finds the path to an enum or one of its variants at the completion site, prefixed like
+    /// imports are if `variant_paths_import_prefix` is enabled.
+    pub(crate) fn variant_use_path(&self, def: hir::ModuleDef) -> Option<hir::ModPath> {
+        if self.config.variant_paths_import_prefix {
+            self.module.find_use_path_prefixed(
+                self.db,
+                def,
+                self.config.insert_use.prefix_kind,
+                self.config.prefer_no_std,
+            )
+        } else {
+            self.module.find_use_path(self.db, def, self.config.prefer_no_std)
+        }
+    }
+
     pub(crate) fn famous_defs(&self) -> FamousDefs<'_, '_> {
         FamousDefs(&self.sema, self.krate)
     }
@@ -508,6 +549,26 @@ pub(crate) fn process_all_names_raw(&self, f: &mut dyn FnMut(Name, ScopeDef)) {
         self.scope.process_all_names(&mut |name, def| f(name, def));
     }
 
+    /// Whether `name` refers to a function or struct in the current scope.
+    pub(crate) fn is_fn_or_struct_name(&self, name: &Name) -> bool {
+        self.fn_and_struct_names
+            .get_or_init(|| {
+                let mut names = FxHashSet::default();
+                self.process_all_names_raw(&mut |name, def| {
+                    if matches!(
+                        def,
+                        ScopeDef::ModuleDef(
+                            hir::ModuleDef::Function(_) | hir::ModuleDef::Adt(hir::Adt::Struct(_))
+                        )
+                    ) {
+                        names.insert(name);
+                    }
+                });
+                names
+            })
+            .contains(name)
+    }
+
     fn is_scope_def_hidden(&self, scope_def: ScopeDef) -> bool {
         if let (Some(attrs), Some(krate)) = (scope_def.attrs(self.db), scope_def.krate(self.db)) {
             return self.is_doc_hidden(&attrs, krate);
@@ -638,6 +699,7 @@ pub(super) fn new(
             expected_type,
             qualifier_ctx,
             locals,
+            fn_and_struct_names: OnceCell::new(),
             depth_from_crate_root,
         };
         Some((ctx, analysis))
@@ -868,6 +868,18 @@ fn classify_name_ref(
                 .map_or(true, |arrow| it.text_range().start() < arrow.text_range().start()),
             None => false,
         };
+        let in_return_position = match it.parent() {
+            Some(parent) if ast::ReturnExpr::can_cast(parent.kind()) => true,
+            Some(parent) => ast::StmtList::cast(parent).map_or(false, |stmt_list| {
+                stmt_list.tail_expr().as_ref() == Some(&expr)
+                    && stmt_list
+                        .syntax()
+                        .parent()
+                        .and_then(|block| block.parent())
+                        .map_or(false, |it| ast::Fn::can_cast(it.kind()))
+            }),
+            None => false,
+        };
 
         PathKind::Expr {
             expr_ctx: ExprCtx {
@@ -882,6 +894,7 @@ fn classify_name_ref(
                 incomplete_let,
                 impl_,
                 in_match_guard,
+                in_return_position,
             },
         }
     };
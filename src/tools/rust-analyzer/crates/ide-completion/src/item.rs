@@ -4,6 +4,7 @@
 
 use hir::{Documentation, Mutability};
 use ide_db::{imports::import_assets::LocatedImport, SnippetCap, SymbolKind};
+use itertools::Itertools;
 use smallvec::SmallVec;
 use stdx::{impl_from, never};
 use syntax::{SmolStr, TextRange, TextSize};
@@ -76,6 +77,26 @@ pub struct CompletionItem {
 
     /// The import data to add to completion's edits.
     pub import_to_add: SmallVec<[LocatedImport; 1]>,
+
+    /// For enum variants, the index of the variant in the declaration order of its enum. Clients
+    /// can use this to order variants as they are declared.
+    pub variant_index: Option<usize>,
+    /// For enum variants whose documentation is left out until the item is resolved, the path to
+    /// the variant starting from the crate root. It's sent along with the resolve data of the
+    /// item, and resolved again to look the documentation up.
+    pub variant_path: Option<String>,
+    /// For enum variants, the name of the variant and the crate it's defined in, to tell the
+    /// variants of the same name from different crates apart.
+    pub(crate) variant_origin: Option<(hir::Name, hir::Crate)>,
+    /// For record enum variants, the names and types of the fields in declaration order, so that
+    /// clients can build their own UI to fill them in.
+    pub variant_fields: Option<Vec<(SmolStr, String)>>,
+    /// Whether this is a tuple enum variant with a single field, like `Id(u32)`, for clients that
+    /// show newtype variants with their own icon.
+    pub is_newtype_variant: bool,
+    /// The bucket this item is sorted in before its relevance is considered, for clients that
+    /// sort purely on the sort text.
+    pub sort_bucket: SortBucket,
 }
 
 // We use custom debug for CompletionItem to make snapshot tests more readable.
@@ -141,6 +162,29 @@ pub struct CompletionRelevance {
     /// }
     /// ```
     pub is_local: bool,
+    /// This is set for tuple variants with a single field whose type matches the type of a local,
+    /// which the variant likely wraps:
+    ///
+    /// ```
+    /// enum E { Id(u32) }
+    /// fn foo(id: u32) {
+    ///     $0 // `E::Id(…)` can wrap `id`
+    /// }
+    /// ```
+    pub has_local_payload: bool,
+    /// This is set for the pattern of the only variant not covered by the other arms of a match:
+    ///
+    /// ```
+    /// enum E { A, B, C }
+    /// fn foo(e: E) {
+    ///     match e {
+    ///         E::A => (),
+    ///         E::B => (),
+    ///         $0 // `E::C` is the last variant to cover
+    ///     }
+    /// }
+    /// ```
+    pub is_last_missing_variant: bool,
     /// This is set when trait items are completed in an impl of that trait.
     pub is_item_from_trait: bool,
     /// This is set when an import is suggested whose name is already imported.
@@ -212,6 +256,8 @@ pub fn score(self) -> u32 {
             exact_name_match,
             type_match,
             is_local,
+            has_local_payload,
+            is_last_missing_variant,
             is_item_from_trait,
             is_name_already_imported,
             requires_import,
@@ -254,6 +300,12 @@ pub fn score(self) -> u32 {
         if is_local {
             score += 1;
         }
+        if has_local_payload {
+            score += 2;
+        }
+        if is_last_missing_variant {
+            score += 10;
+        }
         if is_item_from_trait {
             score += 1;
         }
@@ -271,6 +323,16 @@ pub fn is_relevant(&self) -> bool {
     }
 }
 
+/// A coarse group of completion items, sorted before the relevance of the items. Buckets are
+/// sorted in declaration order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum SortBucket {
+    /// A variant of the expected enum, if `CompletionConfig::group_expected_variants` is set.
+    ExpectedEnumVariant,
+    #[default]
+    Other,
+}
+
 /// The type of the completion item.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum CompletionItemKind {
@@ -346,6 +408,7 @@ pub(crate) fn new(
             detail: None,
             documentation: None,
             lookup: None,
+            doc_aliases: Vec::new(),
             kind: kind.into(),
             text_edit: None,
             deprecated: false,
@@ -353,6 +416,12 @@ pub(crate) fn new(
             relevance: CompletionRelevance::default(),
             ref_match: None,
             imports_to_add: Default::default(),
+            variant_index: None,
+            variant_path: None,
+            variant_origin: None,
+            variant_fields: None,
+            is_newtype_variant: false,
+            sort_bucket: SortBucket::default(),
         }
     }
 
@@ -391,12 +460,19 @@ pub(crate) struct Builder {
     detail: Option<String>,
     documentation: Option<Documentation>,
     lookup: Option<SmolStr>,
+    doc_aliases: Vec<SmolStr>,
     kind: CompletionItemKind,
     text_edit: Option<TextEdit>,
     deprecated: bool,
     trigger_call_info: bool,
     relevance: CompletionRelevance,
     ref_match: Option<(Mutability, TextSize)>,
+    variant_index: Option<usize>,
+    variant_path: Option<String>,
+    variant_origin: Option<(hir::Name, hir::Crate)>,
+    variant_fields: Option<Vec<(SmolStr, String)>>,
+    is_newtype_variant: bool,
+    sort_bucket: SortBucket,
 }
 
 impl Builder {
@@ -416,6 +492,17 @@ pub(crate) fn build(self) -> CompletionItem {
         let mut lookup = self.lookup;
         let insert_text = self.insert_text.unwrap_or_else(|| label.to_string());
 
+        if !self.doc_aliases.is_empty() {
+            // Make the item findable by any of its `#[doc(alias = "...")]`s as well
+            let lookup_doc_aliases = self.doc_aliases.iter().join("");
+            lookup = Some(SmolStr::from(format!(
+                "{}{lookup_doc_aliases}",
+                lookup.as_deref().unwrap_or(&label)
+            )));
+            let doc_aliases = self.doc_aliases.iter().join(", ");
+            label = SmolStr::from(format!("{label} (alias {doc_aliases})"));
+        }
+
         if let [import_edit] = &*self.imports_to_add {
             // snippets can have multiple imports, but normal completions only have up to one
             if let Some(original_path) = import_edit.original_path.as_ref() {
@@ -445,12 +532,22 @@ pub(crate) fn build(self) -> CompletionItem {
             relevance: self.relevance,
             ref_match: self.ref_match,
             import_to_add: self.imports_to_add,
+            variant_index: self.variant_index,
+            variant_path: self.variant_path,
+            variant_origin: self.variant_origin,
+            variant_fields: self.variant_fields,
+            is_newtype_variant: self.is_newtype_variant,
+            sort_bucket: self.sort_bucket,
         }
     }
     pub(crate) fn lookup_by(&mut self, lookup: impl Into<SmolStr>) -> &mut Builder {
         self.lookup = Some(lookup.into());
         self
     }
+    pub(crate) fn doc_aliases(&mut self, doc_aliases: Vec<SmolStr>) -> &mut Builder {
+        self.doc_aliases = doc_aliases;
+        self
+    }
     pub(crate) fn label(&mut self, label: impl Into<SmolStr>) -> &mut Builder {
         self.label = label.into();
         self
@@ -504,6 +601,30 @@ pub(crate) fn set_deprecated(&mut self, deprecated: bool) -> &mut Builder {
         self.deprecated = deprecated;
         self
     }
+    pub(crate) fn variant_index(&mut self, index: usize) -> &mut Builder {
+        self.variant_index = Some(index);
+        self
+    }
+    pub(crate) fn variant_path(&mut self, path: String) -> &mut Builder {
+        self.variant_path = Some(path);
+        self
+    }
+    pub(crate) fn variant_origin(&mut self, name: hir::Name, krate: hir::Crate) -> &mut Builder {
+        self.variant_origin = Some((name, krate));
+        self
+    }
+    pub(crate) fn variant_fields(&mut self, fields: Vec<(SmolStr, String)>) -> &mut Builder {
+        self.variant_fields = Some(fields);
+        self
+    }
+    pub(crate) fn set_newtype_variant(&mut self, is_newtype_variant: bool) -> &mut Builder {
+        self.is_newtype_variant = is_newtype_variant;
+        self
+    }
+    pub(crate) fn sort_bucket(&mut self, bucket: SortBucket) -> &mut Builder {
+        self.sort_bucket = bucket;
+        self
+    }
     pub(crate) fn set_relevance(&mut self, relevance: CompletionRelevance) -> &mut Builder {
         self.relevance = relevance;
         self
@@ -581,9 +702,13 @@ fn relevance_score() {
             vec![Cr { is_private_editable: true, ..default }],
             vec![default],
             vec![Cr { is_local: true, ..default }],
+            vec![Cr { has_local_payload: true, ..default }],
             vec![Cr { type_match: Some(CompletionRelevanceTypeMatch::CouldUnify), ..default }],
             vec![Cr { type_match: Some(CompletionRelevanceTypeMatch::Exact), ..default }],
-            vec![Cr { exact_name_match: true, ..default }],
+            vec![
+                Cr { exact_name_match: true, ..default },
+                Cr { is_last_missing_variant: true, ..default },
+            ],
             vec![Cr { exact_name_match: true, is_local: true, ..default }],
             vec![Cr {
                 exact_name_match: true,
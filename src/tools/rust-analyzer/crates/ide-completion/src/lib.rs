@@ -12,6 +12,7 @@
 mod tests;
 mod snippet;
 
+use hir::Documentation;
 use ide_db::{
     base_db::FilePosition,
     helpers::mod_path_to_ast,
@@ -21,7 +22,7 @@
     },
     items_locator, RootDatabase,
 };
-use syntax::algo;
+use syntax::{algo, ast, AstNode};
 use text_edit::TextEdit;
 
 use crate::{
@@ -33,9 +34,10 @@
 };
 
 pub use crate::{
-    config::{CallableSnippets, CompletionConfig},
+    config::{CallableSnippets, CompletionConfig, VariantItemHook},
     item::{
         CompletionItem, CompletionItemKind, CompletionRelevance, CompletionRelevancePostfixMatch,
+        SortBucket,
     },
     snippet::{Snippet, SnippetScope},
 };
@@ -201,6 +203,10 @@ pub fn completions(
         }
     }
 
+    if config.variant_crate_names {
+        completions.add_variant_crate_names(ctx);
+    }
+
     Some(completions.into())
 }
 
@@ -252,3 +258,36 @@ pub fn resolve_completion_edits(
     algo::diff(scope.as_syntax_node(), new_ast.as_syntax_node()).into_text_edit(&mut import_insert);
     Some(vec![import_insert.finish()])
 }
+
+/// Resolves the documentation of an enum variant completion from its `variant_path`, for clients
+/// that ask for it when the item is resolved. It's the variant's own documentation, which goes
+/// below the generated signatures the item was sent with.
+pub fn resolve_variant_documentation(
+    db: &RootDatabase,
+    config: &CompletionConfig,
+    FilePosition { file_id, offset }: FilePosition,
+    variant_path: &str,
+) -> Option<Documentation> {
+    let _p = profile::span("resolve_variant_documentation");
+    let sema = hir::Semantics::new(db);
+
+    let original_file = sema.parse(file_id);
+    let original_token =
+        syntax::AstNode::syntax(&original_file).token_at_offset(offset).left_biased()?;
+    let scope = sema.scope(&original_token.parent()?)?;
+
+    // The path comes back from the client, so it's parsed like the paths of snippet imports
+    let path = ast::SourceFile::parse(&format!("use {variant_path};"))
+        .syntax_node()
+        .descendants()
+        .find_map(ast::Path::cast)?;
+    if path.syntax().text() != variant_path {
+        return None;
+    }
+    match scope.speculative_resolve(&path)? {
+        hir::PathResolution::Def(hir::ModuleDef::Variant(variant)) => {
+            render::literal::variant_docs(config, db, variant)
+        }
+        _ => None,
+    }
+}
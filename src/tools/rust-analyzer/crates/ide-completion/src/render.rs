@@ -481,13 +481,20 @@ mod tests {
     use std::cmp;
 
     use expect_test::{expect, Expect};
-    use ide_db::SymbolKind;
+    use ide_db::{
+        imports::insert_use::{InsertUseConfig, PrefixKind},
+        SymbolKind,
+    };
     use itertools::Itertools;
 
     use crate::{
         item::CompletionRelevanceTypeMatch,
-        tests::{check_edit, do_completion, get_all_items, TEST_CONFIG},
-        CompletionItem, CompletionItemKind, CompletionRelevance, CompletionRelevancePostfixMatch,
+        tests::{
+            check_edit, check_edit_with_config, do_completion, do_completion_with_config,
+            get_all_items, position, TEST_CONFIG,
+        },
+        CompletionConfig, CompletionItem, CompletionItemKind, CompletionRelevance,
+        CompletionRelevancePostfixMatch, SortBucket,
     };
 
     #[track_caller]
@@ -553,6 +560,8 @@ fn display_relevance(relevance: CompletionRelevance) -> String {
                 ),
                 (relevance.exact_name_match, "name"),
                 (relevance.is_local, "local"),
+                (relevance.has_local_payload, "local_payload"),
+                (relevance.is_last_missing_variant, "last_missing_variant"),
                 (
                     relevance.postfix_match == Some(CompletionRelevancePostfixMatch::Exact),
                     "snippet",
@@ -616,7 +625,561 @@ fn main() { Foo::Fo$0 }
                             Variant,
                         ),
                         lookup: "Foo()",
-                        detail: "Foo(i32, i32)",
+                        detail: "fn(i32, i32) -> Foo",
+                        trigger_call_info: true,
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn tuple_variant_detail_formats() {
+        let fixture = r#"
+enum Foo { Foo(u8, String), Bar { x: u8 } }
+struct String;
+
+fn main() { Foo::$0 }
+"#;
+        let details = |config| {
+            do_completion_with_config(config, fixture, SymbolKind::Variant.into())
+                .into_iter()
+                .map(|it| it.detail.unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(details(TEST_CONFIG), ["Bar { x: u8 }", "fn(u8, String) -> Foo"]);
+        assert_eq!(
+            details(CompletionConfig { tuple_variant_fn_detail: false, ..TEST_CONFIG }),
+            ["Bar { x: u8 }", "Foo(u8, String)"]
+        );
+    }
+
+    #[test]
+    fn unit_variant_detail_has_no_fn_arrow() {
+        let fixture = r#"
+enum E { Unit, Tuple(u8), Record { x: u8 } }
+
+fn main() { E::$0 }
+"#;
+        let details = |config| {
+            do_completion_with_config(config, fixture, SymbolKind::Variant.into())
+                .into_iter()
+                .map(|it| it.detail.unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(details(TEST_CONFIG), ["Record { x: u8 }", "fn(u8) -> E", "Unit"]);
+        assert_eq!(
+            details(CompletionConfig { tuple_variant_fn_detail: false, ..TEST_CONFIG }),
+            ["Record { x: u8 }", "Tuple(u8)", "Unit"]
+        );
+    }
+
+    #[test]
+    fn variant_detail_type_aliases() {
+        let fixture = r#"
+type Id = u64;
+enum E { Record { id: Id, count: u32 }, Tuple(Id) }
+
+fn main() { E::$0 }
+"#;
+        let details = |config| {
+            do_completion_with_config(config, fixture, SymbolKind::Variant.into())
+                .into_iter()
+                .map(|it| it.detail.unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(details(TEST_CONFIG), ["Record { id: Id, count: u32 }", "fn(Id) -> E"]);
+        assert_eq!(
+            details(CompletionConfig { expand_variant_field_aliases: true, ..TEST_CONFIG }),
+            ["Record { id: u64, count: u32 }", "fn(u64) -> E"]
+        );
+    }
+
+    #[test]
+    fn variant_detail_fn_fields() {
+        let details = do_completion(
+            r#"
+//- minicore: fn
+enum E { Callback(fn(u8) -> u8), Handler { on_event: &'static dyn Fn(u8) -> u8 } }
+
+fn main() { E::$0 }
+"#,
+            SymbolKind::Variant.into(),
+        )
+        .into_iter()
+        .map(|it| it.detail.unwrap())
+        .collect::<Vec<_>>();
+        assert_eq!(details, ["fn(fn(u8) -> u8) -> E", "Handler { on_event: &dyn Fn(u8) -> u8 }"]);
+    }
+
+    #[test]
+    fn enum_detail_collapses_many_tuple_fields() {
+        let actual = do_completion_with_config(
+            CompletionConfig { tuple_fields_limit: Some(2), ..TEST_CONFIG },
+            r#"
+enum Foo { Foo(u8, i8, u16, u32, bool) }
+
+fn main() { Foo::Fo$0 }
+"#,
+            SymbolKind::Variant.into(),
+        );
+        expect![[r#"
+            [
+                CompletionItem {
+                    label: "Foo(…)",
+                    source_range: 59..61,
+                    delete: 59..61,
+                    insert: "Foo(${1:()}, ${2:()}, ${3:()}, ${4:()}, ${5:()})$0",
+                    kind: SymbolKind(
+                        Variant,
+                    ),
+                    lookup: "Foo()",
+                    detail: "fn(u8, i8, …3 more) -> Foo",
+                    documentation: Documentation(
+                        "```rust\nfn(u8, i8, u16, u32, bool) -> Foo\n```",
+                    ),
+                    trigger_call_info: true,
+                },
+            ]
+        "#]]
+        .assert_debug_eq(&actual);
+    }
+
+    #[test]
+    fn generated_signatures_with_generic_types_are_code_blocks() {
+        let completions = do_completion_with_config(
+            CompletionConfig { tuple_fields_limit: Some(1), ..TEST_CONFIG },
+            r#"
+//- minicore: option
+struct Vec<T>(T);
+enum E<T> { V(Vec<T>, Option<T>, u8) }
+
+fn main() { E::V$0 }
+"#,
+            SymbolKind::Variant.into(),
+        );
+        let docs = completions
+            .iter()
+            .map(|it| it.documentation.as_ref().map(|docs| docs.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            docs,
+            [Some("```rust\nenum E<T>\n```\n\n```rust\nfn(Vec<T>, Option<T>, u8) -> E<T>\n```")]
+        );
+    }
+
+    #[test]
+    fn enum_detail_includes_doc_summary() {
+        let actual = do_completion_with_config(
+            CompletionConfig { variant_doc_summary_limit: Some(11), ..TEST_CONFIG },
+            r#"
+enum Foo {
+    /// Makes a foo out of a byte.
+    ///
+    /// More details.
+    Foo(u8),
+}
+
+fn main() { Foo::Fo$0 }
+"#,
+            SymbolKind::Variant.into(),
+        );
+        expect![[r#"
+            [
+                CompletionItem {
+                    label: "Foo(…)",
+                    source_range: 109..111,
+                    delete: 109..111,
+                    insert: "Foo(${1:()})$0",
+                    kind: SymbolKind(
+                        Variant,
+                    ),
+                    lookup: "Foo()",
+                    detail: "fn(u8) -> Foo — Makes a foo…",
+                    documentation: Documentation(
+                        "Makes a foo out of a byte.\n\nMore details.",
+                    ),
+                    trigger_call_info: true,
+                },
+            ]
+        "#]]
+        .assert_debug_eq(&actual);
+    }
+
+    #[test]
+    fn compact_variant_docs() {
+        let fixture = r#"
+enum E<T> {
+    /// Makes an `E`
+    /// out of a value.
+    ///
+    /// ```
+    /// let e = E::V(0);
+    /// ```
+    ///
+    /// More   details.
+    V(T),
+}
+
+fn main() { E::V$0 }
+"#;
+        let docs = |compact_variant_docs| {
+            let completions = do_completion_with_config(
+                CompletionConfig { compact_variant_docs, ..TEST_CONFIG },
+                fixture,
+                SymbolKind::Variant.into(),
+            );
+            completions
+                .iter()
+                .map(|it| it.documentation.as_ref().map(|docs| docs.as_str().to_owned()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            docs(false),
+            [Some(
+                "```rust\nenum E<T>\n```\n\nMakes an `E`\nout of a value.\n\n```\nlet e = E::V(0);\n```\n\nMore   details."
+                    .to_owned()
+            )]
+        );
+        // The generated signature is kept
+        assert_eq!(
+            docs(true),
+            [Some(
+                "```rust\nenum E<T>\n```\n\nMakes an `E` out of a value. More details.".to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn variant_repr_detail() {
+        let details = |fixture: &str, variant_repr_detail| {
+            do_completion_with_config(
+                CompletionConfig { variant_repr_detail, ..TEST_CONFIG },
+                fixture,
+                SymbolKind::Variant.into(),
+            )
+            .into_iter()
+            .map(|it| it.detail.unwrap_or_default())
+            .collect::<Vec<_>>()
+        };
+        let fixture = r#"
+#[repr(u16)]
+enum E {
+    A,
+    B = 0x100,
+    C,
+}
+
+fn main() {
+    let _: E = $0;
+}
+"#;
+        assert_eq!(details(fixture, false), ["E::A", "E::B", "E::C"]);
+        assert_eq!(
+            details(fixture, true),
+            ["E::A: u16 = 0", "E::B: u16 = 0x100", "E::C: u16 = 257"]
+        );
+
+        let fixture = r#"
+enum E {
+    A,
+}
+
+fn main() {
+    let _: E = $0;
+}
+"#;
+        assert_eq!(details(fixture, true), ["E::A"]);
+    }
+
+    #[test]
+    fn variant_payload_size() {
+        let details = |fixture: &str| {
+            do_completion_with_config(
+                CompletionConfig { variant_payload_size: true, ..TEST_CONFIG },
+                fixture,
+                SymbolKind::Variant.into(),
+            )
+            .into_iter()
+            .map(|it| it.detail.unwrap_or_default())
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            details(
+                r#"
+enum E {
+    A,
+    B(u8, u32),
+    C { x: u16 },
+}
+
+fn main() {
+    let _: E = $0;
+}
+"#
+            ),
+            ["E::A", "fn(u8, u32) -> E (size: 8 bytes)", "E::C { x: u16 } (size: 4 bytes)"]
+        );
+        // The size is the one of the whole variant, including its tag, not only of the `u32`
+        assert_eq!(
+            details(
+                r#"
+enum T {
+    A(u32),
+    B,
+}
+
+fn main() {
+    let _: T = $0;
+}
+"#
+            ),
+            ["fn(u32) -> T (size: 8 bytes)", "T::B"]
+        );
+        assert_eq!(
+            details(
+                r#"
+enum G<T> {
+    A(T),
+}
+
+fn main() {
+    let _: G<u8> = $0;
+}
+"#
+            ),
+            ["fn(T) -> G<T>"]
+        );
+    }
+
+    #[test]
+    fn variant_record_field_names() {
+        let details = |variant_record_field_names| {
+            do_completion_with_config(
+                CompletionConfig { variant_record_field_names, ..TEST_CONFIG },
+                r#"
+enum E {
+    R { a: u8, b: i32 },
+}
+
+fn main() {
+    let _: E = $0;
+}
+"#,
+                SymbolKind::Variant.into(),
+            )
+            .into_iter()
+            .map(|it| it.detail.unwrap_or_default())
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(details(true), ["E::R { a: u8, b: i32 }"]);
+        assert_eq!(details(false), ["E::R { u8, i32 }"]);
+    }
+
+    #[test]
+    fn variant_repr_detail_keeps_literal_radix() {
+        let details = |fixture: &str| {
+            do_completion_with_config(
+                CompletionConfig { variant_repr_detail: true, ..TEST_CONFIG },
+                fixture,
+                SymbolKind::Variant.into(),
+            )
+            .into_iter()
+            .map(|it| it.detail.unwrap_or_default())
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            details(
+                r#"
+#[repr(u32)]
+enum Flags {
+    A = 0x01,
+    B = 0x02,
+    C = 0xFF_00,
+    D,
+}
+
+fn main() {
+    let _: Flags = $0;
+}
+"#
+            ),
+            [
+                "Flags::A: u32 = 0x01",
+                "Flags::B: u32 = 0x02",
+                "Flags::C: u32 = 0xFF00",
+                "Flags::D: u32 = 65281"
+            ]
+        );
+        assert_eq!(
+            details(
+                r#"
+#[repr(u8)]
+enum Mode {
+    Read = 0b0001,
+    Write = 0b0010,
+    Exec = 4,
+}
+
+fn main() {
+    let _: Mode = $0;
+}
+"#
+            ),
+            ["Mode::Exec: u8 = 4", "Mode::Read: u8 = 0b0001", "Mode::Write: u8 = 0b0010"]
+        );
+    }
+
+    #[test]
+    fn variant_detail_partially_known_args() {
+        let details = |fixture: &str| {
+            do_completion(fixture, SymbolKind::Variant.into())
+                .into_iter()
+                .map(|it| it.detail.unwrap_or_default())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            details(
+                r#"
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+fn main() {
+    let _: Either<u8, _> = $0;
+}
+"#
+            ),
+            ["Either::Left(u8)", "Either::Right(R)"]
+        );
+        assert_eq!(
+            details(
+                r#"
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+fn main() {
+    let _: Either<_, _> = $0;
+}
+"#
+            ),
+            ["Either::Left(L)", "Either::Right(R)"]
+        );
+    }
+
+    #[test]
+    fn enum_variant_docs_include_enum_generics() {
+        check(
+            r#"
+trait Bound {}
+enum E<T: Bound> {
+    /// Docs
+    V(T),
+}
+
+fn main() { E::V$0 }
+"#,
+            SymbolKind::Variant,
+            expect![[r#"
+                [
+                    CompletionItem {
+                        label: "V(…)",
+                        source_range: 75..76,
+                        delete: 75..76,
+                        insert: "V(${1:()})$0",
+                        kind: SymbolKind(
+                            Variant,
+                        ),
+                        lookup: "V()",
+                        detail: "fn(T) -> E<T>",
+                        documentation: Documentation(
+                            "```rust\nenum E<T>\nwhere\n    T: Bound\n```\n\nDocs",
+                        ),
+                        trigger_call_info: true,
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn enum_variant_detail_uses_expected_type_args() {
+        check(
+            r#"
+enum Result<T, E> {
+    Ok(T),
+    Err(E),
+}
+
+fn f() -> Result<i32, u8> { Result::$0 }
+"#,
+            SymbolKind::Variant,
+            expect![[r#"
+                [
+                    CompletionItem {
+                        label: "Err(…)",
+                        source_range: 82..82,
+                        delete: 82..82,
+                        insert: "Err(${1:()})$0",
+                        kind: SymbolKind(
+                            Variant,
+                        ),
+                        lookup: "Err()",
+                        detail: "fn(u8) -> Result<i32, u8>",
+                        documentation: Documentation(
+                            "```rust\nenum Result<T, E>\n```",
+                        ),
+                        relevance: CompletionRelevance {
+                            exact_name_match: false,
+                            type_match: Some(
+                                CouldUnify,
+                            ),
+                            is_local: false,
+                            has_local_payload: false,
+                            is_last_missing_variant: false,
+                            is_item_from_trait: false,
+                            is_name_already_imported: false,
+                            requires_import: false,
+                            is_op_method: false,
+                            is_private_editable: false,
+                            postfix_match: None,
+                            is_definite: false,
+                        },
+                        trigger_call_info: true,
+                    },
+                    CompletionItem {
+                        label: "Ok(…)",
+                        source_range: 82..82,
+                        delete: 82..82,
+                        insert: "Ok(${1:()})$0",
+                        kind: SymbolKind(
+                            Variant,
+                        ),
+                        lookup: "Ok()",
+                        detail: "fn(i32) -> Result<i32, u8>",
+                        documentation: Documentation(
+                            "```rust\nenum Result<T, E>\n```",
+                        ),
+                        relevance: CompletionRelevance {
+                            exact_name_match: false,
+                            type_match: Some(
+                                CouldUnify,
+                            ),
+                            is_local: false,
+                            has_local_payload: false,
+                            is_last_missing_variant: false,
+                            is_item_from_trait: false,
+                            is_name_already_imported: false,
+                            requires_import: false,
+                            is_op_method: false,
+                            is_private_editable: false,
+                            postfix_match: None,
+                            is_definite: false,
+                        },
                         trigger_call_info: true,
                     },
                 ]
@@ -624,6 +1187,25 @@ fn main() { Foo::Fo$0 }
         );
     }
 
+    #[test]
+    fn enum_variant_detail_with_const_params_keeps_declared_types() {
+        let details = do_completion(
+            r#"
+enum E<const N: usize, T> {
+    A([T; N]),
+    B(T),
+}
+
+fn f() -> E<3, u8> { E::$0 }
+"#,
+            SymbolKind::Variant.into(),
+        )
+        .into_iter()
+        .map(|it| it.detail.unwrap_or_default())
+        .collect::<Vec<_>>();
+        assert_eq!(details, ["fn([T; N]) -> E<N, T>", "fn(T) -> E<N, T>"]);
+    }
+
     #[test]
     fn fn_detail_includes_args_and_return_type() {
         check(
@@ -669,20 +1251,586 @@ fn enum_detail_just_name_for_unit() {
             r#"
 enum Foo { Foo }
 
-fn main() { Foo::Fo$0 }
+fn main() { Foo::Fo$0 }
+"#,
+            SymbolKind::Variant,
+            expect![[r#"
+                [
+                    CompletionItem {
+                        label: "Foo",
+                        source_range: 35..37,
+                        delete: 35..37,
+                        insert: "Foo$0",
+                        kind: SymbolKind(
+                            Variant,
+                        ),
+                        detail: "Foo",
+                        trigger_call_info: true,
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn expected_enum_variants_are_sorted_in_first_bucket() {
+        let completions = do_completion_with_config(
+            CompletionConfig { group_expected_variants: true, ..TEST_CONFIG },
+            r#"
+enum Wanted { X, Y }
+enum Other { A }
+use Other::A;
+fn take(w: Wanted) {}
+
+fn main() { take($0) }
+"#,
+            SymbolKind::Variant.into(),
+        );
+        let buckets = completions
+            .iter()
+            .sorted_by_key(|it| it.sort_bucket)
+            .map(|it| (it.label.as_str(), it.sort_bucket))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            buckets,
+            [
+                ("Wanted::X", SortBucket::ExpectedEnumVariant),
+                ("Wanted::Y", SortBucket::ExpectedEnumVariant),
+                ("A", SortBucket::Other),
+            ]
+        );
+    }
+
+    #[test]
+    fn unit_variant_without_expected_type_gets_turbofish() {
+        check_edit(
+            "None",
+            r#"
+//- minicore: option
+fn main() { let x = $0; }
+"#,
+            r#"
+fn main() { let x = None::<${1}>$0; }
+"#,
+        );
+        check_edit(
+            "None",
+            r#"
+//- minicore: option
+fn main() { let x: Option<u8> = $0; }
+"#,
+            r#"
+fn main() { let x: Option<u8> = None$0; }
+"#,
+        );
+        check_edit(
+            "Some()",
+            r#"
+//- minicore: option
+fn main() { let x = $0; }
+"#,
+            r#"
+fn main() { let x = Some(${1:()})$0; }
+"#,
+        );
+    }
+
+    #[test]
+    fn verbose_field_types_are_cut_in_variant_detail() {
+        let completions = do_completion(
+            r#"
+struct Wrapper<T>(T);
+enum E {
+    V(Wrapper<Wrapper<Wrapper<Wrapper<Wrapper<Wrapper<Wrapper<u8>>>>>>>),
+    R { field: Wrapper<Wrapper<Wrapper<Wrapper<Wrapper<Wrapper<u8>>>>>> },
+}
+
+fn main() { E::$0 }
+"#,
+            SymbolKind::Variant.into(),
+        );
+        let details =
+            completions.iter().map(|it| it.detail.as_deref().unwrap()).collect::<Vec<_>>();
+        assert_eq!(
+            details,
+            [
+                "R { field: Wrapper<Wrapper<Wrapper<Wrapper<Wrapper<…>>>>> }",
+                "fn(Wrapper<Wrapper<Wrapper<Wrapper<Wrapper<…>>>>>) -> E",
+            ]
+        );
+    }
+
+    #[test]
+    fn record_variant_brace_spaces() {
+        let before = r#"
+enum E { V { a: u32, b: u32 } }
+fn main() { E::V$0 }
+"#;
+        check_edit(
+            "V{}",
+            before,
+            r#"
+enum E { V { a: u32, b: u32 } }
+fn main() { E::V { a: ${1:()}, b: ${2:()} }$0 }
+"#,
+        );
+        check_edit_with_config(
+            CompletionConfig { record_variant_brace_spaces: false, ..TEST_CONFIG },
+            "V{}",
+            before,
+            r#"
+enum E { V { a: u32, b: u32 } }
+fn main() { E::V {a: ${1:()}, b: ${2:()}}$0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn single_cursor_tuple_variants() {
+        let before = r#"
+enum E { T(u8, u16), R { a: u8 } }
+fn main() { E::$0 }
+"#;
+        check_edit(
+            "T()",
+            before,
+            r#"
+enum E { T(u8, u16), R { a: u8 } }
+fn main() { E::T(${1:()}, ${2:()})$0 }
+"#,
+        );
+        let config = CompletionConfig { single_cursor_tuple_variants: true, ..TEST_CONFIG };
+        check_edit_with_config(
+            config.clone(),
+            "T()",
+            before,
+            r#"
+enum E { T(u8, u16), R { a: u8 } }
+fn main() { E::T($0) }
+"#,
+        );
+        // Record variants keep a tab stop for each field
+        check_edit_with_config(
+            config,
+            "R{}",
+            before,
+            r#"
+enum E { T(u8, u16), R { a: u8 } }
+fn main() { E::R { a: ${1:()} }$0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn option_fields_none() {
+        let before = r#"
+//- minicore: option
+enum E { V { required: u8, optional: Option<u8>, count: u32 } }
+
+fn main() { E::V$0 }
+"#;
+        check_edit(
+            "V{}",
+            before,
+            r#"
+enum E { V { required: u8, optional: Option<u8>, count: u32 } }
+
+fn main() { E::V { required: ${1:()}, optional: ${2:()}, count: ${3:()} }$0 }
+"#,
+        );
+        check_edit_with_config(
+            CompletionConfig { option_fields_none: true, ..TEST_CONFIG },
+            "V{}",
+            before,
+            r#"
+enum E { V { required: u8, optional: Option<u8>, count: u32 } }
+
+fn main() { E::V { required: ${1:()}, optional: None, count: ${2:()} }$0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn todo_variant_fields_in_trait_method_return() {
+        let config = CompletionConfig { todo_variant_fields: true, ..TEST_CONFIG };
+        let before = |body: &str| {
+            format!(
+                r#"
+enum E {{ T(u8, u16), R {{ a: u8 }} }}
+trait Make {{ fn make(&self) -> E; }}
+struct S;
+impl Make for S {{
+    fn make(&self) -> E {{ {body} }}
+}}
+"#
+            )
+        };
+        check_edit_with_config(
+            config.clone(),
+            "T()",
+            &before("E::T$0"),
+            &before("E::T(${1:todo!()}, ${2:todo!()})$0"),
+        );
+        check_edit_with_config(
+            config.clone(),
+            "R{}",
+            &before("E::R$0"),
+            &before("E::R { a: ${1:todo!()} }$0"),
+        );
+        check_edit_with_config(
+            config.clone(),
+            "T()",
+            &before("return E::T$0"),
+            &before("return E::T(${1:todo!()}, ${2:todo!()})$0"),
+        );
+        // Only the returned value gets placeholders, and only when the setting is enabled
+        check_edit_with_config(
+            config,
+            "T()",
+            &before("let e = E::T$0;"),
+            &before("let e = E::T(${1:()}, ${2:()})$0;"),
+        );
+        check_edit("T()", &before("E::T$0"), &before("E::T(${1:()}, ${2:()})$0"));
+    }
+
+    #[test]
+    fn newtype_variant_uses_wrapped_struct_as_placeholder() {
+        let config = CompletionConfig { newtype_variant_type_placeholder: true, ..TEST_CONFIG };
+        let before = |body: &str| {
+            format!(
+                r#"
+struct MyStruct {{ a: u8 }}
+enum E {{ Wrap(MyStruct), Byte(u8), Pair(MyStruct, MyStruct) }}
+fn main() {{ {body} }}
+"#
+            )
+        };
+        check_edit_with_config(
+            config.clone(),
+            "Wrap()",
+            &before("E::Wrap$0"),
+            &before("E::Wrap(${1:MyStruct})$0"),
+        );
+        // Only a single wrapped struct is hinted at
+        check_edit_with_config(
+            config.clone(),
+            "Byte()",
+            &before("E::Byte$0"),
+            &before("E::Byte(${1:()})$0"),
+        );
+        check_edit_with_config(
+            config,
+            "Pair()",
+            &before("E::Pair$0"),
+            &before("E::Pair(${1:()}, ${2:()})$0"),
+        );
+        check_edit("Wrap()", &before("E::Wrap$0"), &before("E::Wrap(${1:()})$0"));
+    }
+
+    #[test]
+    fn variant_item_hook_observes_and_mutates_built_items() {
+        fn hook(variant: hir::Variant, item: &mut CompletionItem) {
+            let docs = item.documentation.as_ref().map_or("no docs", |docs| docs.as_str());
+            item.detail = Some(format!("{}: {docs}", variant.index()));
+        }
+
+        let config = CompletionConfig { variant_item_hook: Some(hook), ..TEST_CONFIG };
+        let details = do_completion_with_config(
+            config,
+            r#"
+enum E {
+    /// First
+    A,
+    B,
+}
+
+fn main() { E::$0 }
+"#,
+            SymbolKind::Variant.into(),
+        )
+        .into_iter()
+        .map(|it| (it.label.to_string(), it.detail))
+        .collect::<Vec<_>>();
+        assert_eq!(
+            details,
+            [
+                ("A".to_owned(), Some("0: First".to_owned())),
+                ("B".to_owned(), Some("1: no docs".to_owned())),
+            ]
+        );
+    }
+
+    #[test]
+    fn variant_detail_names_crate_of_colliding_variants() {
+        let fixture = r#"
+//- /lib.rs crate:dep
+pub enum Error { NotFound }
+//- /main.rs crate:main deps:dep
+use dep::Error::NotFound as DepNotFound;
+enum Error { NotFound }
+fn take(e: Error) {}
+
+fn main() { take($0) }
+"#;
+        let details = |config| {
+            do_completion_with_config(config, fixture, SymbolKind::Variant.into())
+                .into_iter()
+                .map(|it| (it.label.to_string(), it.detail.unwrap()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            details(CompletionConfig { variant_crate_names: true, ..TEST_CONFIG }),
+            [
+                ("DepNotFound".to_owned(), "DepNotFound (from dep)".to_owned()),
+                ("Error::NotFound".to_owned(), "Error::NotFound".to_owned()),
+            ]
+        );
+        assert_eq!(
+            details(TEST_CONFIG),
+            [
+                ("DepNotFound".to_owned(), "DepNotFound".to_owned()),
+                ("Error::NotFound".to_owned(), "Error::NotFound".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn enum_variant_index_in_declaration_order() {
+        let completions = do_completion(
+            r#"
+enum E { C, A(u8), B {} }
+
+fn main() { E::$0 }
+"#,
+            SymbolKind::Variant.into(),
+        );
+        let indices =
+            completions.iter().map(|it| (it.label.as_str(), it.variant_index)).collect::<Vec<_>>();
+        assert_eq!(indices, [("A(…)", Some(1)), ("B {…}", Some(2)), ("C", Some(0))]);
+    }
+
+    #[test]
+    fn variant_prefilter_skips_non_matching_variants() {
+        let others = (0..500).map(|idx| format!("Other{idx}")).join(", ");
+        let fixture =
+            format!("enum E {{ {others}, TargetOne, TargetTwo }}\n\nfn main() {{ E::Targ$0 }}\n");
+        let labels = |config| {
+            do_completion_with_config(config, &fixture, SymbolKind::Variant.into())
+                .into_iter()
+                .map(|it| it.label.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(labels(TEST_CONFIG).len(), 502);
+        assert_eq!(
+            labels(CompletionConfig { variant_prefilter: true, ..TEST_CONFIG }),
+            ["TargetOne", "TargetTwo"]
+        );
+    }
+
+    #[test]
+    fn variant_prefilter_matches_doc_aliases() {
+        let labels = do_completion_with_config(
+            CompletionConfig { variant_prefilter: true, ..TEST_CONFIG },
+            r#"
+enum E {
+    #[doc(alias = "create")]
+    Build,
+    Other,
+}
+
+fn main() { E::cr$0 }
+"#,
+            SymbolKind::Variant.into(),
+        )
+        .into_iter()
+        .map(|it| it.label.to_string())
+        .collect::<Vec<_>>();
+        assert_eq!(labels, ["Build (alias create)"]);
+    }
+
+    #[test]
+    fn newtype_variant_marker() {
+        let completions = do_completion(
+            r#"
+enum E { Id(u32), Pair(u8, u8), Unit, Record { x: u32 } }
+
+fn main() { E::$0 }
+"#,
+            SymbolKind::Variant.into(),
+        );
+        let markers = completions
+            .iter()
+            .map(|it| (it.label.as_str(), it.is_newtype_variant))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            markers,
+            [("Id(…)", true), ("Pair(…)", false), ("Record {…}", false), ("Unit", false)]
+        );
+    }
+
+    #[test]
+    fn enum_variant_path_round_trips() {
+        let (db, position) = position(
+            r#"
+mod m { pub enum E { C, A(u8), B {} } }
+use m::E;
+
+fn main() { E::$0 }
+"#,
+        );
+        let config = CompletionConfig { resolve_variant_docs: true, ..TEST_CONFIG };
+        let completions = crate::completions(&db, &config, position, None).unwrap();
+        let paths = completions
+            .iter()
+            .map(|it| (it.label.to_string(), it.variant_path.clone()))
+            .sorted()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                ("A(…)".to_owned(), Some("crate::m::E::A".to_owned())),
+                ("B {…}".to_owned(), Some("crate::m::E::B".to_owned())),
+                ("C".to_owned(), Some("crate::m::E::C".to_owned())),
+            ]
+        );
+
+        // Clients that don't resolve the documentation get it right away
+        let completions = crate::completions(&db, &TEST_CONFIG, position, None).unwrap();
+        assert!(completions.iter().all(|it| it.variant_path.is_none()));
+    }
+
+    #[test]
+    fn enum_variant_path_resolves_documentation() {
+        let (db, position) = position(
+            r#"
+enum E {
+    /// The first variant
+    A,
+    /// The second variant
+    B,
+}
+
+fn main() { E::$0 }
+"#,
+        );
+        let config = CompletionConfig { resolve_variant_docs: true, ..TEST_CONFIG };
+        let completions = crate::completions(&db, &config, position, None).unwrap();
+        let resolve = |path: &str| {
+            crate::resolve_variant_documentation(&db, &config, position, path)
+                .map(|docs| docs.as_str().to_owned())
+        };
+        let docs = completions
+            .iter()
+            .map(|it| {
+                assert!(it.documentation.is_none());
+                (it.label.to_string(), resolve(it.variant_path.as_deref().unwrap()))
+            })
+            .sorted()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            docs,
+            [
+                ("A".to_owned(), Some("The first variant".to_owned())),
+                ("B".to_owned(), Some("The second variant".to_owned())),
+            ]
+        );
+
+        // Paths sent back by clients are not trusted
+        assert_eq!(resolve("crate::E::C"), None);
+        assert_eq!(resolve("crate::E"), None);
+        assert_eq!(resolve("crate::E::A; fn f() {}"), None);
+    }
+
+    #[test]
+    fn record_variant_fields_in_declaration_order() {
+        let completions = do_completion(
+            r#"
+enum E { R { b: u8, a: Wrap<u16> }, T(u8), U }
+struct Wrap<T>(T);
+
+fn main() { E::$0 }
+"#,
+            SymbolKind::Variant.into(),
+        );
+        let fields = completions
+            .iter()
+            .map(|it| {
+                let fields = it.variant_fields.as_ref().map(|fields| {
+                    fields.iter().map(|(name, ty)| format!("{name}: {ty}")).collect::<Vec<_>>()
+                });
+                (it.label.as_str(), fields)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            [
+                ("R {…}", Some(vec!["b: u8".to_owned(), "a: Wrap<u16>".to_owned()])),
+                ("T(…)", None),
+                ("U", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn variants_of_macro_generated_enum() {
+        // The variants have names in the expansion, so none of them is rendered as unnamed
+        cov_mark::check_count!(render_unnamed_variant, 0);
+        let before = r#"
+macro_rules! make_enum {
+    ($name:ident, $($variant:ident),*) => {
+        enum $name { Generated(u8), $($variant),* }
+    };
+}
+make_enum!(E, A, B);
+
+fn main() { E::$0 }
+"#;
+        let labels = do_completion(before, SymbolKind::Variant.into())
+            .into_iter()
+            .map(|it| it.label.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(labels, ["A", "B", "Generated(…)"]);
+        // Variants spelled out in the macro definition rather than its input still get a snippet
+        check_edit(
+            "Generated()",
+            before,
+            r#"
+macro_rules! make_enum {
+    ($name:ident, $($variant:ident),*) => {
+        enum $name { Generated(u8), $($variant),* }
+    };
+}
+make_enum!(E, A, B);
+
+fn main() { E::Generated(${1:()})$0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn enum_variant_lookup_includes_doc_aliases() {
+        check(
+            r#"
+enum E {
+    #[doc(alias = "create")]
+    Build
+}
+
+fn main() { E::cr$0 }
 "#,
             SymbolKind::Variant,
             expect![[r#"
                 [
                     CompletionItem {
-                        label: "Foo",
-                        source_range: 35..37,
-                        delete: 35..37,
-                        insert: "Foo$0",
+                        label: "Build (alias create)",
+                        source_range: 66..68,
+                        delete: 66..68,
+                        insert: "Build$0",
                         kind: SymbolKind(
                             Variant,
                         ),
-                        detail: "Foo",
+                        lookup: "Buildcreate",
+                        detail: "Build",
                         trigger_call_info: true,
                     },
                 ]
@@ -735,13 +1883,15 @@ fn main() { let _: m::Spam = S$0 }
                             Variant,
                         ),
                         lookup: "Spam::Bar()",
-                        detail: "m::Spam::Bar(i32)",
+                        detail: "fn(i32) -> Spam",
                         relevance: CompletionRelevance {
                             exact_name_match: false,
                             type_match: Some(
                                 Exact,
                             ),
                             is_local: false,
+                            has_local_payload: false,
+                            is_last_missing_variant: false,
                             is_item_from_trait: false,
                             is_name_already_imported: false,
                             requires_import: false,
@@ -768,6 +1918,8 @@ fn main() { let _: m::Spam = S$0 }
                                 Exact,
                             ),
                             is_local: false,
+                            has_local_payload: false,
+                            is_last_missing_variant: false,
                             is_item_from_trait: false,
                             is_name_already_imported: false,
                             requires_import: false,
@@ -783,6 +1935,309 @@ fn main() { let _: m::Spam = S$0 }
         )
     }
 
+    #[test]
+    fn variant_shadowed_by_same_named_fn() {
+        cov_mark::check!(variant_shadowed_by_same_named_item);
+        check_edit(
+            "E::Bar",
+            r#"
+enum E { Bar, Baz }
+use E::*;
+fn Bar() {}
+
+fn main() -> E { Ba$0 }
+"#,
+            r#"
+enum E { Bar, Baz }
+use E::*;
+fn Bar() {}
+
+fn main() -> E { E::Bar$0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn variant_paths_import_prefix() {
+        let config = |prefix_kind| CompletionConfig {
+            variant_paths_import_prefix: true,
+            insert_use: InsertUseConfig { prefix_kind, ..TEST_CONFIG.insert_use },
+            ..TEST_CONFIG
+        };
+        let shadowed = r#"
+enum E { Bar, Baz }
+use E::*;
+fn Bar() {}
+
+fn main() -> E { Ba$0 }
+"#;
+        let in_module = r#"
+mod m {
+    pub enum E { V }
+}
+
+fn main() -> m::E { $0 }
+"#;
+
+        check_edit_with_config(
+            config(PrefixKind::ByCrate),
+            "E::Bar",
+            shadowed,
+            r#"
+enum E { Bar, Baz }
+use E::*;
+fn Bar() {}
+
+fn main() -> E { crate::E::Bar$0 }
+"#,
+        );
+        check_edit_with_config(
+            config(PrefixKind::ByCrate),
+            "E::V",
+            in_module,
+            r#"
+mod m {
+    pub enum E { V }
+}
+
+fn main() -> m::E { crate::m::E::V$0 }
+"#,
+        );
+        check_edit_with_config(
+            config(PrefixKind::BySelf),
+            "E::Bar",
+            shadowed,
+            r#"
+enum E { Bar, Baz }
+use E::*;
+fn Bar() {}
+
+fn main() -> E { self::E::Bar$0 }
+"#,
+        );
+        check_edit_with_config(
+            config(PrefixKind::BySelf),
+            "E::V",
+            in_module,
+            r#"
+mod m {
+    pub enum E { V }
+}
+
+fn main() -> m::E { self::m::E::V$0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn variant_after_typed_qualifier() {
+        check_edit(
+            "Some()",
+            r#"
+enum E { Some(u8), Other }
+
+fn main() -> E { E::So$0 }
+"#,
+            r#"
+enum E { Some(u8), Other }
+
+fn main() -> E { E::Some(${1:()})$0 }
+"#,
+        );
+        check_edit(
+            "Some()",
+            r#"
+mod m {
+    pub enum E { Some(u8), Other }
+}
+
+fn main() -> m::E { m::E::So$0 }
+"#,
+            r#"
+mod m {
+    pub enum E { Some(u8), Other }
+}
+
+fn main() -> m::E { m::E::Some(${1:()})$0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn variant_parens_independent_of_callable_snippets() {
+        let before = r#"
+enum E { V(u8) }
+fn f(x: u8) {}
+
+fn main() { $0 }
+"#;
+        let after = |completed: &str| {
+            format!("\nenum E {{ V(u8) }}\nfn f(x: u8) {{}}\n\nfn main() {{ {completed} }}\n")
+        };
+        let variant_before = before.replace("$0", "E::$0");
+
+        let no_variant_parens =
+            CompletionConfig { add_call_parenthesis_for_variants: false, ..TEST_CONFIG };
+        check_edit_with_config(no_variant_parens.clone(), "V", &variant_before, &after("E::V$0"));
+        check_edit_with_config(no_variant_parens, "f", before, &after("f(${1:x})$0"));
+
+        let no_callable = CompletionConfig { callable: None, ..TEST_CONFIG };
+        check_edit_with_config(
+            no_callable.clone(),
+            "V()",
+            &variant_before,
+            &after("E::V(${1:()})$0"),
+        );
+        check_edit_with_config(no_callable, "f", before, &after("f"));
+    }
+
+    #[test]
+    fn tuple_variant_without_snippets() {
+        let before = r#"
+enum E { V(u8, u8), R { x: u8 } }
+
+fn main() { E::$0 }
+"#;
+        let after = |completed: &str| {
+            format!("\nenum E {{ V(u8, u8), R {{ x: u8 }} }}\n\nfn main() {{ {completed} }}\n")
+        };
+        let no_snippets = CompletionConfig { snippet_cap: None, ..TEST_CONFIG };
+        check_edit_with_config(no_snippets.clone(), "V()", before, &after("E::V()"));
+        check_edit_with_config(no_snippets, "R{}", before, &after("E::R"));
+    }
+
+    #[test]
+    fn full_variant_paths() {
+        let config = CompletionConfig { full_variant_paths: true, ..TEST_CONFIG };
+        check_edit_with_config(
+            config.clone(),
+            "V()",
+            r#"
+mod m {
+    pub enum E { V(u8), W }
+}
+
+fn main() -> m::E { $0 }
+"#,
+            r#"
+mod m {
+    pub enum E { V(u8), W }
+}
+
+fn main() -> m::E { crate::m::E::V(${1:()})$0 }
+"#,
+        );
+        check_edit_with_config(
+            config,
+            "W",
+            r#"
+mod m {
+    pub enum E { V(u8), W }
+}
+use m::E::*;
+
+fn main() -> m::E { W$0 }
+"#,
+            r#"
+mod m {
+    pub enum E { V(u8), W }
+}
+use m::E::*;
+
+fn main() -> m::E { crate::m::E::W$0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn no_import_edits_inserts_qualified_variant_path() {
+        let fixture = r#"
+mod m {
+    pub enum E {
+        /// Wraps a byte.
+        Variant(u8),
+    }
+}
+
+fn main() { Varian$0 }
+"#;
+        let variant = |config| {
+            do_completion_with_config(config, fixture, SymbolKind::Variant.into())
+                .into_iter()
+                .find(|item| item.lookup() == "Variant()")
+                .unwrap()
+        };
+        let config = CompletionConfig { no_import_edits: true, ..TEST_CONFIG };
+        let imported = variant(TEST_CONFIG);
+        let qualified = variant(config.clone());
+        assert_eq!(imported.import_to_add.len(), 1);
+        assert!(qualified.import_to_add.is_empty());
+        assert_eq!(qualified.detail, imported.detail);
+        assert_eq!(qualified.documentation, imported.documentation);
+        check_edit_with_config(
+            config,
+            "Variant()",
+            fixture,
+            r#"
+mod m {
+    pub enum E {
+        /// Wraps a byte.
+        Variant(u8),
+    }
+}
+
+fn main() { m::E::Variant(${1:()})$0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn last_missing_variant_is_boosted() {
+        let items = do_completion(
+            r#"
+enum Shape { Circle, Square, Triangle }
+
+fn foo(shape: Shape) {
+    match shape {
+        Shape::Circle => (),
+        Shape::Square => (),
+        Shape::$0
+    }
+}
+"#,
+            CompletionItemKind::Binding,
+        );
+        let top = items.iter().max_by_key(|item| item.relevance.score()).unwrap();
+        assert_eq!(top.label.as_str(), "Triangle");
+        let boosted = items
+            .iter()
+            .filter(|item| item.relevance.is_last_missing_variant)
+            .map(|item| item.label.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(boosted, ["Triangle"]);
+    }
+
+    #[test]
+    fn variant_import_alternatives() {
+        let fixture = r#"
+mod m {
+    pub enum E { Variant(u8) }
+}
+
+fn main() { Varian$0 }
+"#;
+        assert_eq!(do_completion(fixture, SymbolKind::Variant.into()).len(), 1);
+        let config = CompletionConfig { variant_import_alternatives: true, ..TEST_CONFIG };
+        let items = do_completion_with_config(config, fixture, SymbolKind::Variant.into())
+            .into_iter()
+            .map(|item| (item.label.to_string(), item.import_to_add.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [("Variant(…)".to_owned(), 1), ("crate::m::E::Variant(…)".to_owned(), 0)]
+        );
+    }
+
     #[test]
     fn sets_deprecated_flag_in_items() {
         check(
@@ -846,6 +2301,8 @@ fn foo() { A { the$0 } }
                                 CouldUnify,
                             ),
                             is_local: false,
+                            has_local_payload: false,
+                            is_last_missing_variant: false,
                             is_item_from_trait: false,
                             is_name_already_imported: false,
                             requires_import: false,
@@ -996,6 +2453,188 @@ fn foo(s: S) { s.$0 }
         )
     }
 
+    #[test]
+    fn no_parens_for_tuple_constructor_as_fn() {
+        cov_mark::check!(no_parens_for_tuple_constructor_as_fn);
+        check_edit(
+            "Wrap",
+            r#"
+//- minicore: fn
+enum E { Wrap(u8) }
+struct S;
+impl S {
+    fn map<U, F: FnOnce(u8) -> U>(self, f: F) -> U { f(0) }
+}
+
+fn main() { S.map(E::W$0); }
+"#,
+            r#"
+enum E { Wrap(u8) }
+struct S;
+impl S {
+    fn map<U, F: FnOnce(u8) -> U>(self, f: F) -> U { f(0) }
+}
+
+fn main() { S.map(E::Wrap$0); }
+"#,
+        );
+    }
+
+    #[test]
+    fn no_parens_for_tuple_constructor_as_fn_pointer() {
+        cov_mark::check!(no_parens_for_tuple_constructor_as_fn);
+        check_edit(
+            "Wrap",
+            r#"
+enum E { Wrap(u8) }
+
+fn main() { let f: fn(u8) -> E = E::W$0; }
+"#,
+            r#"
+enum E { Wrap(u8) }
+
+fn main() { let f: fn(u8) -> E = E::Wrap$0; }
+"#,
+        );
+        // The constructor doesn't fit the signature, maybe it's called to return the value
+        check_edit(
+            "Wrap()",
+            r#"
+enum E { Wrap(u8) }
+
+fn main() { let f: fn(u8, u8) -> E = E::W$0; }
+"#,
+            r#"
+enum E { Wrap(u8) }
+
+fn main() { let f: fn(u8, u8) -> E = E::Wrap(${1:()})$0; }
+"#,
+        );
+    }
+
+    #[test]
+    fn record_variant_uses_shorthand_for_matching_locals() {
+        check_edit(
+            "V{}",
+            r#"
+enum E { V { a: u8, b: u8, c: u32 } }
+
+fn main() {
+    let a = 0u8;
+    let b = "";
+    E::$0
+}
+"#,
+            r#"
+enum E { V { a: u8, b: u8, c: u32 } }
+
+fn main() {
+    let a = 0u8;
+    let b = "";
+    E::V { ${1:a}, b: ${2:()}, c: ${3:()} }$0
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unit_variant_never_gets_parens() {
+        check_edit(
+            "Unit",
+            r#"
+enum E { Unit, Wrap(u8) }
+fn take(e: E) {}
+
+fn main() { take(E::U$0); }
+"#,
+            r#"
+enum E { Unit, Wrap(u8) }
+fn take(e: E) {}
+
+fn main() { take(E::Unit$0); }
+"#,
+        );
+        check_edit(
+            "Unit",
+            r#"
+//- minicore: fn
+enum E { Unit, Wrap(u8) }
+struct S;
+impl S {
+    fn map<U, F: FnOnce(u8) -> U>(self, f: F) -> U { f(0) }
+}
+
+fn main() { S.map(E::U$0); }
+"#,
+            r#"
+enum E { Unit, Wrap(u8) }
+struct S;
+impl S {
+    fn map<U, F: FnOnce(u8) -> U>(self, f: F) -> U { f(0) }
+}
+
+fn main() { S.map(E::Unit$0); }
+"#,
+        );
+        check_edit(
+            "E::Unit",
+            r#"
+enum E { Unit, Wrap(u8) }
+
+fn main() -> E { U$0 }
+"#,
+            r#"
+enum E { Unit, Wrap(u8) }
+
+fn main() -> E { E::Unit$0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn no_snippet_for_variant_in_attribute_macro_input() {
+        cov_mark::check!(variant_in_macro_expansion_without_snippet);
+        check_edit(
+            "Wrap()",
+            r#"
+//- proc_macros: identity
+enum E { Wrap(u8) }
+
+#[proc_macros::identity]
+fn main() {
+    let e: E = W$0;
+}
+"#,
+            r#"
+enum E { Wrap(u8) }
+
+#[proc_macros::identity]
+fn main() {
+    let e: E = E::Wrap();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn snippet_for_variant_in_fn_like_macro_input() {
+        check_edit(
+            "Wrap()",
+            r#"
+macro_rules! id { ($($tt:tt)*) => { $($tt)* }; }
+enum E { Wrap(u8) }
+
+fn main() { id!(E::W$0); }
+"#,
+            r#"
+macro_rules! id { ($($tt:tt)*) => { $($tt)* }; }
+enum E { Wrap(u8) }
+
+fn main() { id!(E::Wrap(${1:()})$0); }
+"#,
+        );
+    }
+
     #[test]
     fn no_call_parens_if_fn_ptr_needed() {
         cov_mark::check!(no_call_parens_if_fn_ptr_needed);
@@ -1670,6 +3309,25 @@ fn baz() []
         );
     }
 
+    #[test]
+    fn newtype_variant_wrapping_local_is_boosted() {
+        check_relevance_for_kinds(
+            r#"
+enum E { Flag(bool), Unit, Id(u32) }
+fn main() {
+    let id = 0u32;
+    E::$0
+}
+"#,
+            &[CompletionItemKind::SymbolKind(SymbolKind::Variant)],
+            expect![[r#"
+                ev Id(…) [local_payload]
+                ev Flag(…) []
+                ev Unit []
+            "#]],
+        );
+    }
+
     #[test]
     fn postfix_exact_match_is_high_priority() {
         cov_mark::check!(postfix_exact_match_is_high_priority);
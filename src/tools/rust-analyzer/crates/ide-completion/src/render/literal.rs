@@ -1,11 +1,14 @@
 //! Renderer for `enum` variants.
 
-use hir::{db::HirDatabase, Documentation, HasAttrs, StructKind};
-use ide_db::SymbolKind;
+use hir::{db::HirDatabase, Documentation, HasAttrs, HirDisplay, StructKind};
+use ide_db::{active_parameter::callable_for_token, SymbolKind};
+use itertools::Itertools;
+use stdx::format_to;
+use syntax::{ast, SmolStr};
 
 use crate::{
-    context::{CompletionContext, PathCompletionCtx, PathKind},
-    item::{Builder, CompletionItem},
+    context::{CompletionContext, PathCompletionCtx, PathKind, Qualified},
+    item::{Builder, CompletionItem, SortBucket},
     render::{
         compute_type_match,
         variant::{
@@ -14,7 +17,7 @@
         },
         RenderContext,
     },
-    CompletionItemKind, CompletionRelevance,
+    CompletionConfig, CompletionItemKind, CompletionRelevance,
 };
 
 pub(crate) fn render_variant_lit(
@@ -28,7 +31,108 @@ pub(crate) fn render_variant_lit(
     let db = ctx.db();
 
     let name = local_name.unwrap_or_else(|| variant.name(db));
-    render(ctx, path_ctx, Variant::EnumVariant(variant), name, path)
+    if name == hir::Name::missing() {
+        return Some(render_unnamed_variant(ctx, variant));
+    }
+    let path = match path {
+        _ if ctx.completion.config.no_import_edits && ctx.import_to_add.is_some() => {
+            ctx.import_to_add.as_ref().map(|import| import.import_path.clone())
+        }
+        _ if ctx.completion.config.full_variant_paths
+            && matches!(path_ctx.qualified, Qualified::No) =>
+        {
+            full_path(ctx.completion, variant)
+        }
+        None if matches!(path_ctx.qualified, Qualified::No) => {
+            disambiguating_path(ctx.completion, &name, variant)
+        }
+        path => path,
+    };
+    // Skip the variants the client would filter out anyway before doing the work of rendering them
+    if ctx.completion.config.variant_prefilter {
+        // The doc aliases are part of the lookup string the client matches against
+        let mut text = path.as_ref().map_or_else(|| name.to_string(), |path| path.to_string());
+        variant.attrs(db).doc_aliases().iter().for_each(|alias| text.push_str(alias));
+        if !fuzzy_matches(&text, ctx.completion.typed_prefix()) {
+            return None;
+        }
+    }
+    // Full variant paths are still looked up by the variant's own name, and replace the import
+    let is_full_path = ctx.completion.config.full_variant_paths
+        || (ctx.completion.config.no_import_edits && ctx.import_to_add.is_some());
+    render(ctx, path_ctx, Variant::EnumVariant(variant), name, path, is_full_path)
+}
+
+/// Renders the variant with its full path and without an import, offered next to the completion
+/// importing the variant when `variant_import_alternatives` is enabled.
+pub(crate) fn render_variant_lit_with_full_path(
+    ctx: RenderContext<'_>,
+    path_ctx: &PathCompletionCtx,
+    variant: hir::Variant,
+) -> Option<Builder> {
+    let path = full_path(ctx.completion, variant)?;
+    let name = variant.name(ctx.db());
+    render(ctx.import_to_add(None), path_ctx, Variant::EnumVariant(variant), name, Some(path), true)
+}
+
+/// Whether the characters of `typed` appear in `text` in order, ignoring case, as clients match
+/// completions against the typed text.
+fn fuzzy_matches(text: &str, typed: &str) -> bool {
+    let mut text = text.chars().flat_map(char::to_lowercase);
+    typed.chars().flat_map(char::to_lowercase).all(|typed| text.any(|it| it == typed))
+}
+
+/// Renders a variant without a name in the source, as can be produced by macros expanding broken
+/// input. It's labelled with a name made up from its position in the enum, but there is no path to
+/// insert for it, so the completed text is left as it is.
+fn render_unnamed_variant(ctx: RenderContext<'_>, variant: hir::Variant) -> Builder {
+    cov_mark::hit!(render_unnamed_variant);
+    let db = ctx.db();
+    let source_range = ctx.source_range();
+    let token = &ctx.completion.original_token;
+    let current_text = match source_range.checked_sub(token.text_range().start()) {
+        Some(range) if token.text_range().contains_range(source_range) => &token.text()[range],
+        _ => "",
+    };
+    let mut item = CompletionItem::new(
+        CompletionItemKind::SymbolKind(SymbolKind::Variant),
+        source_range,
+        format!("variant{}", variant.index()),
+    );
+    item.insert_text(current_text)
+        .detail(variant.parent_enum(db).name(db).to_string())
+        .variant_index(variant.index())
+        .set_relevance(ctx.completion_relevance());
+    item
+}
+
+/// Returns the path to the variant starting from the crate root, used when the
+/// `full_variant_paths` config is enabled.
+fn full_path(ctx: &CompletionContext<'_>, variant: hir::Variant) -> Option<hir::ModPath> {
+    let mut path = ctx.module.find_use_path_prefixed(
+        ctx.db,
+        hir::ModuleDef::from(variant.parent_enum(ctx.db)),
+        hir::PrefixKind::ByCrate,
+        ctx.config.prefer_no_std,
+    )?;
+    path.push_segment(variant.name(ctx.db));
+    Some(path)
+}
+
+/// If `name` also refers to a function or struct in the current scope, returns the path to the
+/// variant qualified by its enum, so that the completion does not collide with the other item.
+fn disambiguating_path(
+    ctx: &CompletionContext<'_>,
+    name: &hir::Name,
+    variant: hir::Variant,
+) -> Option<hir::ModPath> {
+    if !ctx.is_fn_or_struct_name(name) {
+        return None;
+    }
+    cov_mark::hit!(variant_shadowed_by_same_named_item);
+    let mut path = ctx.variant_use_path(hir::ModuleDef::from(variant.parent_enum(ctx.db)))?;
+    path.push_segment(variant.name(ctx.db));
+    Some(path)
 }
 
 pub(crate) fn render_struct_literal(
@@ -42,7 +146,7 @@ pub(crate) fn render_struct_literal(
     let db = ctx.db();
 
     let name = local_name.unwrap_or_else(|| strukt.name(db));
-    render(ctx, path_ctx, Variant::Struct(strukt), name, path)
+    render(ctx, path_ctx, Variant::Struct(strukt), name, path, false)
 }
 
 fn render(
@@ -51,21 +155,78 @@ fn render(
     thing: Variant,
     name: hir::Name,
     path: Option<hir::ModPath>,
+    is_full_path: bool,
 ) -> Option<Builder> {
     let db = completion.db;
     let mut kind = thing.kind(db);
+    // An already typed qualifier is not part of the source range, so repeating it would paste it
+    // twice, as in `E::E::Variant`
+    let path = path.filter(|_| !matches!(path_ctx.qualified, Qualified::With { .. }));
+    let fields = thing.fields(completion)?;
     let should_add_parens = match &path_ctx {
+        // Unit variants and structs are values on their own, whatever the context expects
+        _ if kind == StructKind::Unit => false,
+        _ if matches!(thing, Variant::EnumVariant(_))
+            && !completion.config.add_call_parenthesis_for_variants =>
+        {
+            false
+        }
         PathCompletionCtx { has_call_parens: true, .. } => false,
         PathCompletionCtx { kind: PathKind::Use | PathKind::Type { .. }, .. } => false,
+        // Tuple constructors can be passed as functions, as in `.map(E::Wrap)`
+        _ if kind == StructKind::Tuple && expects_fn_value(completion, thing, fields.len()) => {
+            cov_mark::hit!(no_parens_for_tuple_constructor_as_fn);
+            false
+        }
         _ => true,
     };
 
-    let fields = thing.fields(completion)?;
+    // Substitute the generic arguments of the expected type, so that `Ok` renders as `Ok(i32)`
+    // where a `Result<i32, E>` is expected
+    let expected_ty =
+        completion.expected_type.as_ref().filter(|ty| ty.as_adt() == Some(thing.adt(db)));
+    // Arguments left to inference, as in `Either<u8, _>`, keep the name of their parameter. The
+    // arguments can only be given for type parameters, so the declared types are kept for enums
+    // with const parameters, whose arguments would be shifted.
+    let params = hir::GenericDef::from(thing.adt(db)).type_params(db);
+    let has_const_params = params.iter().any(|param| param.split(db).is_left());
+    let expected_args = expected_ty.filter(|_| !has_const_params).map(|ty| {
+        let params = params.into_iter().filter_map(|param| param.split(db).right());
+        ty.type_arguments()
+            .zip(params)
+            .map(|(arg, param)| if arg.is_unknown() { param.ty(db) } else { arg })
+            .collect::<Vec<_>>()
+    });
+    let field_tys = fields
+        .iter()
+        .map(|field| match &expected_args {
+            Some(args) => field.ty_with_args(db, args),
+            None => field.ty(db),
+        })
+        .collect::<Vec<_>>();
+    // Taken before `kind` is reset for items completed without parentheses
+    let is_newtype_variant = kind == StructKind::Tuple && field_tys.len() == 1;
+    // A newtype variant is likely completed to wrap a local of the type of its field
+    let has_local_payload = match &field_tys[..] {
+        [field_ty] if kind == StructKind::Tuple && matches!(thing, Variant::EnumVariant(_)) => {
+            completion.locals.values().any(|local| local.ty(db) == *field_ty)
+        }
+        _ => false,
+    };
+    // Clients handling imports themselves get the path the import would have brought into scope
+    let no_import_edits =
+        completion.config.no_import_edits && matches!(thing, Variant::EnumVariant(_));
+    let full_variant_path =
+        is_full_path && matches!(thing, Variant::EnumVariant(_)) && path.is_some();
     let (qualified_name, short_qualified_name, qualified) = match path {
         Some(path) => {
+            let short_len = if full_variant_path { 1 } else { 2 };
             let short = hir::ModPath::from_segments(
                 hir::PathKind::Plain,
-                path.segments().iter().skip(path.segments().len().saturating_sub(2)).cloned(),
+                path.segments()
+                    .iter()
+                    .skip(path.segments().len().saturating_sub(short_len))
+                    .cloned(),
             );
             (path, short, true)
         }
@@ -73,22 +234,113 @@ fn render(
     };
     let (qualified_name, escaped_qualified_name) =
         (qualified_name.unescaped().to_string(), qualified_name.to_string());
-    let snippet_cap = ctx.snippet_cap();
+    // Tab stops in the input of a macro can end up at odd places once the macro is expanded again,
+    // so insert plain text there
+    let snippet_cap = ctx.snippet_cap().filter(|_| {
+        let in_expansion = completion.is_in_macro_expansion();
+        if in_expansion {
+            cov_mark::hit!(variant_in_macro_expansion_without_snippet);
+        }
+        !in_expansion
+    });
 
+    // Trait methods are often implemented with placeholders first, `todo!()` lets them compile
+    let placeholder = match &path_ctx.kind {
+        PathKind::Expr { expr_ctx }
+            if completion.config.todo_variant_fields
+                && matches!(thing, Variant::EnumVariant(_))
+                && expr_ctx.in_return_position
+                && expr_ctx.impl_.as_ref().map_or(false, |impl_| impl_.trait_().is_some()) =>
+        {
+            "todo!()".to_owned()
+        }
+        // The name of a wrapped struct hints at what to put in a newtype variant
+        _ => match &field_tys[..] {
+            [field_ty]
+                if completion.config.newtype_variant_type_placeholder
+                    && kind == StructKind::Tuple
+                    && matches!(thing, Variant::EnumVariant(_)) =>
+            {
+                match field_ty.as_adt() {
+                    Some(hir::Adt::Struct(strukt)) => strukt.name(db).to_string(),
+                    _ => "()".to_owned(),
+                }
+            }
+            _ => "()".to_owned(),
+        },
+    };
+
+    // Clients building their own UI for the fields get them with their types, as in the detail
+    let variant_fields = (kind == StructKind::Record && matches!(thing, Variant::EnumVariant(_)))
+        .then(|| {
+            fields
+                .iter()
+                .zip(&field_tys)
+                .map(|(field, ty)| (field.name(db).to_smol_str(), ty.display(db).to_string()))
+                .collect::<Vec<_>>()
+        });
+    let single_cursor = snippet_cap.is_some()
+        && kind == StructKind::Tuple
+        && should_add_parens
+        && matches!(thing, Variant::EnumVariant(_))
+        && completion.config.single_cursor_tuple_variants;
     let mut rendered = match kind {
         StructKind::Tuple if should_add_parens => {
-            render_tuple_lit(db, snippet_cap, &fields, &escaped_qualified_name)
+            // Tuple variants are constructor functions, so their detail can read like one
+            let fn_detail = matches!(thing, Variant::EnumVariant(_))
+                && completion.config.tuple_variant_fn_detail;
+            let ret_ty = fn_detail.then(|| match &expected_args {
+                Some(args) => thing.adt(db).ty_with_args(db, args).display(db).to_string(),
+                None => thing.ty(db).display(db).to_string(),
+            });
+            let fields = fields.into_iter().zip(field_tys).collect::<Vec<_>>();
+            let mut rendered = render_tuple_lit(
+                completion,
+                snippet_cap,
+                &fields,
+                &escaped_qualified_name,
+                ret_ty.as_deref(),
+                completion.config.tuple_fields_limit,
+                &placeholder,
+            );
+            // Leave the fields to the user, with only the final tab stop inside the parenthesis
+            if single_cursor {
+                rendered.literal = format!("{escaped_qualified_name}($0)");
+            }
+            rendered
         }
         StructKind::Record if should_add_parens => {
-            render_record_lit(db, snippet_cap, &fields, &escaped_qualified_name)
+            let fields = fields.into_iter().zip(field_tys).collect::<Vec<_>>();
+            // Struct literals keep the rustfmt style
+            let brace_spaces = !matches!(thing, Variant::EnumVariant(_))
+                || completion.config.record_variant_brace_spaces;
+            render_record_lit(
+                completion,
+                snippet_cap,
+                &fields,
+                &escaped_qualified_name,
+                brace_spaces,
+                &placeholder,
+                matches!(thing, Variant::EnumVariant(_)),
+            )
         }
+        // Unit variants are values rather than constructors, so they never read like a function,
+        // whatever `tuple_variant_fn_detail` says
         _ => RenderedLiteral {
             literal: escaped_qualified_name.clone(),
             detail: escaped_qualified_name,
+            full_detail: None,
         },
     };
+    if snippet_cap.is_some() && kind == StructKind::Unit {
+        let args = uninferable_type_args(completion, path_ctx, thing);
+        if args > 0 {
+            let placeholders = (1..=args).map(|idx| format!("${{{idx}}}")).join(", ");
+            format_to!(rendered.literal, "::<{placeholders}>");
+        }
+    }
 
-    if snippet_cap.is_some() {
+    if snippet_cap.is_some() && !single_cursor {
         rendered.literal.push_str("$0");
     }
 
@@ -110,29 +362,237 @@ fn render(
     );
 
     item.lookup_by(lookup);
-    item.detail(rendered.detail);
+    item.doc_aliases(thing.doc_aliases(db));
+    if let Variant::EnumVariant(variant) = thing {
+        item.variant_index(variant.index())
+            .variant_origin(variant.name(db), variant.module(db).krate())
+            .set_newtype_variant(is_newtype_variant);
+        if let Some(fields) = variant_fields {
+            item.variant_fields(fields);
+        }
+        let is_expected_enum =
+            completion.expected_type.as_ref().and_then(|ty| ty.as_adt()) == Some(thing.adt(db));
+        if completion.config.group_expected_variants && is_expected_enum {
+            item.sort_bucket(SortBucket::ExpectedEnumVariant);
+        }
+    }
+    let mut detail = rendered.detail;
+    // The representation of the variant matters to FFI code, as in `A: u8 = 0`
+    if let Variant::EnumVariant(variant) = thing {
+        if completion.config.variant_repr_detail {
+            let repr = repr_int_type(db, variant.parent_enum(db));
+            if let Some((repr, value)) = repr.zip(variant.eval(db).ok()) {
+                format_to!(detail, ": {repr} = {}", format_discriminant(db, variant, value));
+            }
+        }
+        if completion.config.variant_payload_size {
+            if let Some(size) = variant_size(db, variant) {
+                format_to!(detail, " (size: {size} bytes)");
+            }
+        }
+    }
+    let detail = match (thing, completion.config.variant_doc_summary_limit) {
+        (Variant::EnumVariant(_), Some(limit)) => {
+            match thing.docs(db).and_then(|docs| doc_summary(&docs, limit)) {
+                Some(summary) => format!("{detail} — {summary}"),
+                None => detail,
+            }
+        }
+        _ => detail,
+    };
+    item.detail(detail);
 
     match snippet_cap {
         Some(snippet_cap) => item.insert_snippet(snippet_cap, rendered.literal).trigger_call_info(),
         None => item.insert_text(rendered.literal),
     };
 
-    item.set_documentation(thing.docs(db)).set_deprecated(thing.is_deprecated(&ctx));
+    // Generated signatures go above the item's own docs: the generic parameters of the variant's
+    // enum, and the fields that were left out of the detail. They are put in code blocks, so that
+    // clients rendering the docs as Markdown leave types like `Vec<T>` alone.
+    let signatures = thing
+        .enum_signature(db)
+        .into_iter()
+        .chain(rendered.full_detail)
+        .map(|signature| format!("```rust\n{signature}\n```"))
+        .collect::<Vec<_>>();
+    // Clients resolving the documentation look the variant up again by its path, to get the
+    // variant's own docs then
+    let resolved_path = match thing {
+        Variant::EnumVariant(variant) if completion.config.resolve_variant_docs => {
+            full_path(completion, variant)
+        }
+        _ => None,
+    };
+    let own_docs = match thing {
+        _ if resolved_path.is_some() => None,
+        Variant::EnumVariant(variant) => variant_docs(completion.config, db, variant),
+        Variant::Struct(_) => thing.docs(db),
+    };
+    if let Some(path) = resolved_path {
+        item.variant_path(path.to_string());
+    }
+    let docs = if signatures.is_empty() {
+        own_docs
+    } else {
+        let docs = signatures.into_iter().chain(own_docs.map(String::from)).join("\n\n");
+        Some(Documentation::new(docs))
+    };
+    item.set_documentation(docs).set_deprecated(thing.is_deprecated(&ctx));
 
     let ty = thing.ty(db);
     item.set_relevance(CompletionRelevance {
         type_match: compute_type_match(ctx.completion, &ty),
+        has_local_payload,
         ..ctx.completion_relevance()
     });
 
     super::path_ref_match(completion, path_ctx, &ty, &mut item);
 
-    if let Some(import_to_add) = ctx.import_to_add {
+    if let Some(import_to_add) =
+        ctx.import_to_add.filter(|_| !full_variant_path && !no_import_edits)
+    {
         item.add_import(import_to_add);
     }
     Some(item)
 }
 
+/// Returns the documentation written for the variant, compacted if `compact_variant_docs` is
+/// enabled. The generated signatures are not part of it.
+pub(crate) fn variant_docs(
+    config: &CompletionConfig,
+    db: &dyn HirDatabase,
+    variant: hir::Variant,
+) -> Option<Documentation> {
+    let docs = variant.docs(db);
+    if config.compact_variant_docs {
+        docs.and_then(|docs| compact_docs(&docs))
+    } else {
+        docs
+    }
+}
+
+/// Returns the integer type given to the enum by its `#[repr]` attribute, as in `#[repr(C, u8)]`.
+fn repr_int_type(db: &dyn HirDatabase, enum_: hir::Enum) -> Option<String> {
+    const INT_TYPES: &[&str] =
+        &["u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"];
+    enum_.attrs(db).by_key("repr").tt_values().find_map(|tt| {
+        tt.to_string()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .find(|word| INT_TYPES.contains(word))
+            .map(str::to_owned)
+    })
+}
+
+/// Gets the size of the variant's layout, which accounts for the tag and the padding between the
+/// fields. Unit variants have no payload, and the size is unknown if the layout of the enum can't
+/// be computed, as for generic enums.
+fn variant_size(db: &dyn HirDatabase, variant: hir::Variant) -> Option<u64> {
+    if variant.fields(db).is_empty() {
+        return None;
+    }
+    variant.layout(db).ok().map(|layout| layout.size.bytes())
+}
+
+/// Formats the discriminant in the radix of the variant's explicit discriminant literal, with as
+/// many digits, as in `0x01` for flags. Other discriminants are formatted in decimal.
+fn format_discriminant(db: &dyn HirDatabase, variant: hir::Variant, value: i128) -> String {
+    let Some(ast::Expr::Literal(literal)) = variant.value(db) else {
+        return value.to_string();
+    };
+    let ast::LiteralKind::IntNumber(number) = literal.kind() else {
+        return value.to_string();
+    };
+    let (prefix, digits, _) = number.split_into_parts();
+    let width = digits.chars().filter(|&c| c != '_').count();
+    match number.radix() {
+        _ if value < 0 => value.to_string(),
+        ast::Radix::Hexadecimal if digits.chars().any(|c| c.is_ascii_uppercase()) => {
+            format!("{prefix}{value:0width$X}")
+        }
+        ast::Radix::Hexadecimal => format!("{prefix}{value:0width$x}"),
+        ast::Radix::Octal => format!("{prefix}{value:0width$o}"),
+        ast::Radix::Binary => format!("{prefix}{value:0width$b}"),
+        ast::Radix::Decimal => value.to_string(),
+    }
+}
+
+/// Returns the first non-empty line of the documentation, cut to at most `limit` characters.
+fn doc_summary(docs: &Documentation, limit: usize) -> Option<String> {
+    let line = docs.as_str().lines().map(str::trim).find(|line| !line.is_empty())?;
+    if line.chars().count() <= limit {
+        return Some(line.to_owned());
+    }
+    let mut summary = line.chars().take(limit).collect::<String>().trim_end().to_owned();
+    summary.push('…');
+    Some(summary)
+}
+
+/// Collapses the documentation written for the item into a single paragraph, joining its lines with
+/// single spaces and leaving out code blocks.
+fn compact_docs(docs: &Documentation) -> Option<Documentation> {
+    let mut in_code_block = false;
+    let compact = docs
+        .as_str()
+        .lines()
+        .filter(|line| {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+                return false;
+            }
+            !in_code_block
+        })
+        .flat_map(str::split_whitespace)
+        .join(" ");
+    (!compact.is_empty()).then(|| Documentation::new(compact))
+}
+
+/// Returns the number of generic arguments a unit variant needs as a turbofish, as in `None::<u8>`,
+/// if nothing tells the type of its generic enum: unit variants have no fields to infer the type
+/// parameters from.
+fn uninferable_type_args(
+    ctx: &CompletionContext<'_>,
+    path_ctx: &PathCompletionCtx,
+    thing: Variant,
+) -> usize {
+    let Variant::EnumVariant(variant) = thing else { return 0 };
+    let has_expected_type = ctx.expected_type.as_ref().map_or(false, |ty| !ty.is_unknown());
+    if has_expected_type || !matches!(path_ctx.kind, PathKind::Expr { .. }) {
+        return 0;
+    }
+    hir::GenericDef::from(variant.parent_enum(ctx.db)).type_params(ctx.db).len()
+}
+
+/// Whether a function value is expected at the completion position, either by the expected type
+/// or by the declared type of the parameter the completion is an argument for. The latter covers
+/// generic parameters like `F: FnOnce(T) -> U`, whose expected type is often still unknown.
+fn expects_fn_value(ctx: &CompletionContext<'_>, thing: Variant, n_fields: usize) -> bool {
+    if let Some(ty) = ctx.expected_type.as_ref().filter(|ty| !ty.is_unknown()) {
+        // A function pointer, as in `let f: fn(u8) -> E = E::Wrap`, has to have the signature of the
+        // constructor, otherwise the constructor is more likely to be called
+        if let Some(callable) = ty.as_callable(ctx.db).filter(|_| ty.is_fn()) {
+            let ret = callable.return_type();
+            return callable.n_params() == n_fields
+                && (ret.is_unknown() || ret.as_adt() == Some(thing.adt(ctx.db)));
+        }
+        if ty.impls_fnonce(ctx.db) {
+            return true;
+        }
+    }
+    let Some((callable, Some(idx))) = callable_for_token(&ctx.sema, ctx.token.clone()) else {
+        return false;
+    };
+    let hir::CallableKind::Function(func) = callable.kind() else { return false };
+    let params = if callable.receiver_param(ctx.db).is_some() {
+        func.params_without_self(ctx.db)
+    } else {
+        func.assoc_fn_params(ctx.db)
+    };
+    params
+        .get(idx)
+        .map_or(false, |param| !param.ty().is_unknown() && param.ty().impls_fnonce(ctx.db))
+}
+
 #[derive(Clone, Copy)]
 enum Variant {
     Struct(hir::Struct),
@@ -140,6 +600,16 @@ enum Variant {
 }
 
 impl Variant {
+    /// The signature of the variant's enum, if it has generic parameters.
+    fn enum_signature(self, db: &dyn HirDatabase) -> Option<String> {
+        let Variant::EnumVariant(it) = self else { return None };
+        let enum_ = it.parent_enum(db);
+        if hir::GenericDef::from(enum_).params(db).is_empty() {
+            return None;
+        }
+        Some(enum_.display(db).to_string())
+    }
+
     fn fields(self, ctx: &CompletionContext<'_>) -> Option<Vec<hir::Field>> {
         let fields = match self {
             Variant::Struct(it) => it.fields(ctx.db),
@@ -177,6 +647,13 @@ fn docs(self, db: &dyn HirDatabase) -> Option<Documentation> {
         }
     }
 
+    fn doc_aliases(self, db: &dyn HirDatabase) -> Vec<SmolStr> {
+        match self {
+            Variant::Struct(it) => it.attrs(db).doc_aliases(),
+            Variant::EnumVariant(it) => it.attrs(db).doc_aliases(),
+        }
+    }
+
     fn is_deprecated(self, ctx: &RenderContext<'_>) -> bool {
         match self {
             Variant::Struct(it) => ctx.is_deprecated(it),
@@ -184,6 +661,13 @@ fn is_deprecated(self, ctx: &RenderContext<'_>) -> bool {
         }
     }
 
+    fn adt(self, db: &dyn HirDatabase) -> hir::Adt {
+        match self {
+            Variant::Struct(it) => it.into(),
+            Variant::EnumVariant(it) => it.parent_enum(db).into(),
+        }
+    }
+
     fn ty(self, db: &dyn HirDatabase) -> hir::Type {
         match self {
             Variant::Struct(it) => it.ty(db),
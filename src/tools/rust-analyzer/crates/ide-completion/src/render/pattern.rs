@@ -1,9 +1,14 @@
 //! Renderer for patterns.
 
+use std::iter;
+
 use hir::{db::HirDatabase, HasAttrs, Name, StructKind};
-use ide_db::SnippetCap;
+use ide_db::{
+    helpers::{mod_path_to_ast, variant_pat},
+    SnippetCap,
+};
 use itertools::Itertools;
-use syntax::SmolStr;
+use syntax::{SmolStr, T};
 
 use crate::{
     context::{ParamContext, ParamKind, PathCompletionCtx, PatternContext},
@@ -14,6 +19,54 @@
     CompletionItem, CompletionItemKind,
 };
 
+/// Renders a single pattern for all the variants of an enum that are not matched yet, joined by
+/// `|` as in `E::A | E::B(_)`, followed by the `=>` of the match arm if it's still missing.
+pub(crate) fn render_missing_variants_pat(
+    ctx: RenderContext<'_>,
+    missing_variants: &[hir::Variant],
+) -> Option<CompletionItem> {
+    let _p = profile::span("render_missing_variants_pat");
+    let db = ctx.db();
+    let completion = ctx.completion;
+
+    // A single variant is already completed on its own, and the fields of record variants would
+    // have to be bound in every alternative of the pattern
+    if missing_variants.len() < 2
+        || missing_variants
+            .iter()
+            .any(|variant| variant.kind(db) == StructKind::Record && !variant.fields(db).is_empty())
+    {
+        return None;
+    }
+
+    // The patterns are shared with the "add missing match arms" assist
+    let pat = missing_variants
+        .iter()
+        .map(|&variant| {
+            let path = completion.variant_use_path(hir::ModuleDef::from(variant))?;
+            variant_pat(db, mod_path_to_ast(&path), variant)
+        })
+        .collect::<Option<Vec<_>>>()?
+        .iter()
+        .join(" | ");
+
+    // The arm might already have its `=>`, as when completing `$0 => ()`
+    let has_fat_arrow =
+        iter::successors(completion.original_token.next_token(), |token| token.next_token())
+            .find(|token| !token.kind().is_trivia())
+            .map_or(false, |token| token.kind() == T![=>]);
+
+    let mut item =
+        CompletionItem::new(CompletionItemKind::Binding, ctx.source_range(), pat.as_str());
+    item.detail(&pat);
+    match ctx.snippet_cap() {
+        Some(snippet_cap) if has_fat_arrow => item.insert_snippet(snippet_cap, format!("{pat}$0")),
+        Some(snippet_cap) => item.insert_snippet(snippet_cap, format!("{pat} => $0")),
+        None => item.insert_text(pat),
+    };
+    Some(item.build())
+}
+
 pub(crate) fn render_struct_pat(
     ctx: RenderContext<'_>,
     pattern_ctx: &PatternContext,
@@ -84,7 +137,7 @@ pub(crate) fn render_variant_pat(
         }
     };
 
-    Some(build_completion(
+    let mut item = build_completion(
         ctx,
         label,
         lookup,
@@ -92,7 +145,10 @@ pub(crate) fn render_variant_pat(
         variant,
         enum_ty,
         pattern_ctx.missing_variants.contains(&variant),
-    ))
+    );
+    // The only variant left to cover is most likely the one the new arm is written for
+    item.relevance.is_last_missing_variant = pattern_ctx.missing_variants == [variant];
+    Some(item)
 }
 
 fn build_completion(
@@ -6,67 +6,155 @@
 use itertools::Itertools;
 use syntax::SmolStr;
 
+/// The length after which the types of fields are cut with an ellipsis in the `detail` of a
+/// literal, so that verbose types like `impl Iterator<Item = …>` don't take up the whole line.
+const FIELD_TYPE_DISPLAY_LIMIT: usize = 40;
+
 /// A rendered struct, union, or enum variant, split into fields for actual
 /// auto-completion (`literal`, using `field: ()`) and display in the
 /// completions menu (`detail`, using `field: type`).
 pub(crate) struct RenderedLiteral {
     pub(crate) literal: String,
     pub(crate) detail: String,
+    /// The full `detail`, if some of the fields had to be left out of it.
+    pub(crate) full_detail: Option<String>,
 }
 
 /// Render a record type (or sub-type) to a `RenderedCompound`. Use `None` for
-/// the `name` argument for an anonymous type.
+/// the `name` argument for an anonymous type. Fields with a local of the same
+/// name and type in scope use the field init shorthand, the others get `placeholder`
+/// as their value, or `None` without a tab stop for `Option` fields of enum variants if
+/// `option_fields_none` is set. The `detail` of enum variants leaves out the field names
+/// unless `variant_record_field_names` is set. `brace_spaces` controls whether the
+/// `literal` has spaces inside its braces.
 pub(crate) fn render_record_lit(
-    db: &dyn HirDatabase,
+    ctx: &CompletionContext<'_>,
     snippet_cap: Option<SnippetCap>,
-    fields: &[hir::Field],
+    fields: &[(hir::Field, hir::Type)],
     path: &str,
+    brace_spaces: bool,
+    placeholder: &str,
+    is_enum_variant: bool,
 ) -> RenderedLiteral {
     if snippet_cap.is_none() {
-        return RenderedLiteral { literal: path.to_string(), detail: path.to_string() };
+        return RenderedLiteral {
+            literal: path.to_string(),
+            detail: path.to_string(),
+            full_detail: None,
+        };
     }
-    let completions = fields.iter().enumerate().format_with(", ", |(idx, field), f| {
+    let db = ctx.db;
+    let option_none = is_enum_variant && ctx.config.option_fields_none;
+    let option_enum = option_none.then(|| ctx.famous_defs().core_option_Option()).flatten();
+    // The fields left as `None` get no tab stop, so the tab stops are numbered separately
+    let mut tab_stop = 0;
+    let completions = fields.iter().format_with(", ", |(field, ty), f| {
+        let name = field.name(db);
+        let has_matching_local = ctx.locals.get(&name).map_or(false, |local| local.ty(db) == *ty);
+        let is_option =
+            option_enum.map_or(false, |option| ty.as_adt() == Some(hir::Adt::Enum(option)));
+        if is_option && !has_matching_local {
+            return f(&format_args!("{name}: None"));
+        }
+        tab_stop += 1;
         if snippet_cap.is_some() {
-            f(&format_args!("{}: ${{{}:()}}", field.name(db), idx + 1))
+            if has_matching_local {
+                f(&format_args!("${{{}:{}}}", tab_stop, name))
+            } else {
+                f(&format_args!("{}: ${{{}:{placeholder}}}", name, tab_stop))
+            }
         } else {
-            f(&format_args!("{}: ()", field.name(db)))
+            f(&format_args!("{}: {placeholder}", name))
         }
     });
 
-    let types = fields.iter().format_with(", ", |field, f| {
-        f(&format_args!("{}: {}", field.name(db), field.ty(db).display(db)))
+    let expand_aliases = ctx.config.expand_variant_field_aliases;
+    let field_names = !is_enum_variant || ctx.config.variant_record_field_names;
+    let types = fields.iter().format_with(", ", |(field, ty), f| {
+        let ty = display_field_ty(db, field, ty, expand_aliases);
+        if field_names {
+            f(&format_args!("{}: {ty}", field.name(db)))
+        } else {
+            f(&ty)
+        }
     });
 
-    RenderedLiteral {
-        literal: format!("{path} {{ {completions} }}"),
-        detail: format!("{path} {{ {types} }}"),
-    }
+    let literal = if brace_spaces {
+        format!("{path} {{ {completions} }}")
+    } else {
+        format!("{path} {{{completions}}}")
+    };
+    RenderedLiteral { literal, detail: format!("{path} {{ {types} }}"), full_detail: None }
 }
 
 /// Render a tuple type (or sub-type) to a `RenderedCompound`. Use `None` for
-/// the `name` argument for an anonymous type.
+/// the `name` argument for an anonymous type. If `ret_ty` is given, the `detail`
+/// reads like a constructor function, as in `fn(u8) -> E`. If there are more than
+/// `fields_limit` fields, the remaining ones are collapsed in the `detail`. The
+/// fields of the `literal` get `placeholder` as their value. Without snippets,
+/// the `literal` only has empty parentheses.
 pub(crate) fn render_tuple_lit(
-    db: &dyn HirDatabase,
+    ctx: &CompletionContext<'_>,
     snippet_cap: Option<SnippetCap>,
-    fields: &[hir::Field],
+    fields: &[(hir::Field, hir::Type)],
     path: &str,
+    ret_ty: Option<&str>,
+    fields_limit: Option<usize>,
+    placeholder: &str,
 ) -> RenderedLiteral {
     if snippet_cap.is_none() {
-        return RenderedLiteral { literal: path.to_string(), detail: path.to_string() };
+        return RenderedLiteral {
+            literal: format!("{path}()"),
+            detail: path.to_string(),
+            full_detail: None,
+        };
     }
     let completions = fields.iter().enumerate().format_with(", ", |(idx, _), f| {
         if snippet_cap.is_some() {
-            f(&format_args!("${{{}:()}}", idx + 1))
+            f(&format_args!("${{{}:{placeholder}}}", idx + 1))
         } else {
-            f(&format_args!("()"))
+            f(&format_args!("{placeholder}"))
         }
     });
 
-    let types = fields.iter().format_with(", ", |field, f| f(&field.ty(db).display(db)));
+    let (db, expand_aliases) = (ctx.db, ctx.config.expand_variant_field_aliases);
+    let types = |fields: &[(hir::Field, hir::Type)]| {
+        fields
+            .iter()
+            .format_with(", ", |(field, ty), f| f(&display_field_ty(db, field, ty, expand_aliases)))
+            .to_string()
+    };
+    let signature = |fields: &str| match ret_ty {
+        Some(ret_ty) => format!("fn({fields}) -> {ret_ty}"),
+        None => format!("{path}({fields})"),
+    };
+    let detail = signature(&types(fields));
+
+    let (detail, full_detail) = match fields_limit {
+        Some(limit) if fields.len() > limit => {
+            let more = format!("…{} more", fields.len() - limit);
+            let collapsed = match &fields[..limit] {
+                [] => signature(&more),
+                shown => signature(&format!("{}, {more}", types(shown))),
+            };
+            (collapsed, Some(detail))
+        }
+        _ => (detail, None),
+    };
+
+    RenderedLiteral { literal: format!("{path}({completions})"), detail, full_detail }
+}
 
-    RenderedLiteral {
-        literal: format!("{path}({completions})"),
-        detail: format!("{path}({types})"),
+fn display_field_ty(
+    db: &dyn HirDatabase,
+    field: &hir::Field,
+    ty: &hir::Type,
+    expand_aliases: bool,
+) -> String {
+    // Type aliases are resolved in `ty`, so the alias has to come from how the field is written
+    match field.type_alias(db) {
+        Some(alias) if !expand_aliases => alias.name(db).to_string(),
+        _ => ty.display_truncated(db, Some(FIELD_TYPE_DISPLAY_LIMIT)).to_string(),
     }
 }
 
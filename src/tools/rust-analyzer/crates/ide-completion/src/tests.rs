@@ -75,6 +75,29 @@ union Union { field: i32 }
     },
     snippets: Vec::new(),
     limit: None,
+    tuple_fields_limit: None,
+    full_variant_paths: false,
+    variant_doc_summary_limit: None,
+    group_expected_variants: false,
+    tuple_variant_fn_detail: true,
+    add_call_parenthesis_for_variants: true,
+    variant_crate_names: false,
+    record_variant_brace_spaces: true,
+    compact_variant_docs: false,
+    todo_variant_fields: false,
+    newtype_variant_type_placeholder: false,
+    no_import_edits: false,
+    variant_repr_detail: false,
+    variant_paths_import_prefix: false,
+    single_cursor_tuple_variants: false,
+    variant_import_alternatives: false,
+    expand_variant_field_aliases: false,
+    variant_prefilter: false,
+    option_fields_none: false,
+    variant_payload_size: false,
+    variant_record_field_names: true,
+    resolve_variant_docs: false,
+    variant_item_hook: None,
 };
 
 pub(crate) fn completion_list(ra_fixture: &str) -> String {
@@ -45,7 +45,7 @@ fn function()    fn()
             st Tuple
             st Unit
             un Union
-            ev TupleV(…)     TupleV(u32)
+            ev TupleV(…)     fn(u32) -> Enum
             bt u32
             kw crate::
             kw false
@@ -138,7 +138,7 @@ fn local_func() fn()
             st Unit
             tp TypeParam
             un Union
-            ev TupleV(…)    TupleV(u32)
+            ev TupleV(…)    fn(u32) -> Enum
             bt u32
             kw const
             kw crate::
@@ -198,7 +198,7 @@ fn function() fn()
             st Unit
             tt Trait
             un Union
-            ev TupleV(…)  TupleV(u32)
+            ev TupleV(…)  fn(u32) -> Enum
             ?? Unresolved
         "#]],
     );
@@ -548,7 +548,7 @@ fn func() {
             fn assoc_fn()  fn()
             ta AssocType   type AssocType = ()
             ev RecordV {…} RecordV { field: u32 }
-            ev TupleV(…)   TupleV(u32)
+            ev TupleV(…)   fn(u32) -> Enum
             ev UnitV       UnitV
         "#]],
     );
@@ -742,3 +742,100 @@ fn f(x: EnumAlias<u8>) {
         "#]],
     );
 }
+
+#[test]
+fn completes_missing_fieldless_variants_as_one_arm() {
+    check_edit(
+        "E::B | E::C",
+        r#"
+enum E { A, B, C }
+
+fn foo(e: E) {
+    match e {
+        E::A => (),
+        $0
+    }
+}
+"#,
+        r#"
+enum E { A, B, C }
+
+fn foo(e: E) {
+    match e {
+        E::A => (),
+        E::B | E::C => $0
+    }
+}
+"#,
+    );
+    check_edit(
+        "E::B | E::C",
+        r#"
+enum E { A, B, C }
+
+fn foo(e: E) {
+    match e {
+        E::A => (),
+        $0 => (),
+    }
+}
+"#,
+        r#"
+enum E { A, B, C }
+
+fn foo(e: E) {
+    match e {
+        E::A => (),
+        E::B | E::C$0 => (),
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn missing_variants_pats_match_add_missing_match_arms() {
+    // Keep in sync with `patterns_match_missing_variants_completion` in the "add missing match
+    // arms" assist, both build their patterns with `ide_db::helpers::variant_pat`
+    check_edit(
+        "E::B(_, _) | E::C",
+        r#"
+enum E { A, B(u8, u16), C }
+
+fn foo(e: E) {
+    match e {
+        E::A => (),
+        $0
+    }
+}
+"#,
+        r#"
+enum E { A, B(u8, u16), C }
+
+fn foo(e: E) {
+    match e {
+        E::A => (),
+        E::B(_, _) | E::C => $0
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn missing_record_variants_are_not_joined() {
+    // The fields would have to be bound in every alternative
+    let completions = completion_list(
+        r#"
+enum E { A, B { x: u8 }, C }
+
+fn foo(e: E) {
+    match e {
+        E::A => (),
+        $0
+    }
+}
+"#,
+    );
+    assert!(!completions.contains(" | "), "{completions}");
+}
@@ -1,9 +1,9 @@
 //! Random assortment of ide helpers for high-level ide features that don't fit in any other module.
 
-use std::collections::VecDeque;
+use std::{collections::VecDeque, iter};
 
 use base_db::{FileId, SourceDatabaseExt};
-use hir::{Crate, ItemInNs, ModuleDef, Name, Semantics};
+use hir::{Crate, HasSource, ItemInNs, ModuleDef, Name, Semantics};
 use syntax::{
     ast::{self, make},
     AstToken, SyntaxKind, SyntaxToken, TokenAtOffset,
@@ -54,6 +54,31 @@ pub fn mod_path_to_ast(path: &hir::ModPath) -> ast::Path {
     make::path_from_segments(segments, is_abs)
 }
 
+/// Builds the pattern matching every value of the variant at `path`, as written in a match over
+/// the variants of an enum: `E::V(_)` for tuple variants, `E::V { field }` for record variants and
+/// `E::V` for unit variants.
+///
+/// Both the "add missing match arms" assist and the completion of the missing variants of a match
+/// build their patterns with this, so that they don't diverge.
+pub fn variant_pat(db: &RootDatabase, path: ast::Path, variant: hir::Variant) -> Option<ast::Pat> {
+    // FIXME: use HIR for this; it doesn't currently expose struct vs. tuple vs. unit variants though
+    let pat = match variant.source(db)?.value.kind() {
+        ast::StructKind::Tuple(field_list) => {
+            let pats = iter::repeat(make::wildcard_pat().into()).take(field_list.fields().count());
+            make::tuple_struct_pat(path, pats).into()
+        }
+        ast::StructKind::Record(field_list) => {
+            let pats = field_list
+                .fields()
+                .map(|f| f.name().map(|name| make::ext::simple_ident_pat(name).into()))
+                .collect::<Option<Vec<_>>>()?;
+            make::record_pat(path, pats).into()
+        }
+        ast::StructKind::Unit => make::path_pat(path),
+    };
+    Some(pat)
+}
+
 /// Iterates all `ModuleDef`s and `Impl` blocks of the given file.
 pub fn visit_file_defs(
     sema: &Semantics<'_, RootDatabase>,
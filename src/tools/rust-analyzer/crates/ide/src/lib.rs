@@ -108,7 +108,7 @@ macro_rules! eprintln {
 };
 pub use ide_completion::{
     CallableSnippets, CompletionConfig, CompletionItem, CompletionItemKind, CompletionRelevance,
-    Snippet, SnippetScope,
+    Snippet, SnippetScope, SortBucket, VariantItemHook,
 };
 pub use ide_db::{
     base_db::{
@@ -587,6 +587,18 @@ pub fn resolve_completion_edits(
             .unwrap_or_default())
     }
 
+    /// Resolves the documentation of an enum variant completion from its `variant_path`.
+    pub fn variant_documentation(
+        &self,
+        config: &CompletionConfig,
+        position: FilePosition,
+        variant_path: &str,
+    ) -> Cancellable<Option<Documentation>> {
+        self.with_db(|db| {
+            ide_completion::resolve_variant_documentation(db, config, position, variant_path)
+        })
+    }
+
     /// Computes the set of diagnostics for the given file.
     pub fn diagnostics(
         &self,
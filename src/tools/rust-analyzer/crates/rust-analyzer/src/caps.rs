@@ -185,6 +185,27 @@ pub(crate) fn completion_item_edit_resolve(caps: &ClientCapabilities) -> bool {
     })() == Some(true)
 }
 
+/// Whether the client resolves the documentation of completion items. Items are only resolved if
+/// the edits can be resolved as well, see `completions_resolve_provider`.
+pub(crate) fn completion_item_docs_resolve(caps: &ClientCapabilities) -> bool {
+    completion_item_edit_resolve(caps)
+        && (|| {
+            Some(
+                caps.text_document
+                    .as_ref()?
+                    .completion
+                    .as_ref()?
+                    .completion_item
+                    .as_ref()?
+                    .resolve_support
+                    .as_ref()?
+                    .properties
+                    .iter()
+                    .any(|cap_string| cap_string.as_str() == "documentation"),
+            )
+        })() == Some(true)
+}
+
 fn completion_item(config: &Config) -> Option<CompletionOptionsCompletionItem> {
     Some(CompletionOptionsCompletionItem {
         label_details_support: Some(config.completion_label_details_support()),
@@ -30,7 +30,7 @@
 use vfs::AbsPathBuf;
 
 use crate::{
-    caps::completion_item_edit_resolve,
+    caps::{completion_item_docs_resolve, completion_item_edit_resolve},
     diagnostics::DiagnosticsMapConfig,
     line_index::PositionEncoding,
     lsp_ext::{self, negotiated_encoding, WorkspaceSymbolSearchKind, WorkspaceSymbolSearchScope},
@@ -202,12 +202,20 @@ struct ConfigData {
         completion_autoself_enable: bool        = "true",
         /// Whether to add parenthesis and argument snippets when completing function.
         completion_callable_snippets: CallableCompletionDef  = "\"fill_arguments\"",
+        /// Whether to always insert enum variants with their full path from the crate root, instead of a shorter path or an import.
+        completion_fullVariantPaths_enable: bool = "false",
+        /// Whether to sort the variants of the expected enum before all other completions, for
+        /// clients that sort completions by their sort text only.
+        completion_groupExpectedVariants_enable: bool = "false",
         /// Maximum number of completions to return. If `None`, the limit is infinite.
         completion_limit: Option<usize> = "null",
         /// Whether to show postfix snippets like `dbg`, `if`, `not`, etc.
         completion_postfix_enable: bool         = "true",
         /// Enables completions of private items and fields that are defined in the current workspace even if they are not visible at the current position.
         completion_privateEditable_enable: bool = "false",
+        /// Whether to put spaces inside the braces of record enum variant snippets, as in
+        /// `E::V { a: ${1:()} }` instead of `E::V {a: ${1:()}}`.
+        completion_recordVariantBraceSpaces_enable: bool = "true",
         /// Custom completion snippets.
         // NOTE: Keep this list in sync with the feature docs of user snippets.
         completion_snippets_custom: FxHashMap<String, SnippetDef> = r#"{
@@ -251,6 +259,68 @@ struct ConfigData {
                 "scope": "expr"
             }
         }"#,
+        /// Maximum number of tuple fields to show in the detail of a struct or enum variant
+        /// completion before collapsing the rest. If `None`, all fields are shown.
+        completion_tupleFields_limit: Option<usize> = "null",
+        /// Whether to show the detail of tuple variant completions like the signature of their
+        /// constructor function, as in `fn(u8) -> E`, instead of `V(u8)`.
+        completion_tupleVariantFnDetail_enable: bool = "true",
+        /// Whether to add a tab stop for each field when completing tuple enum variants, as in
+        /// `V(${1:()}, ${2:()})`. If disabled, only the cursor is placed inside the parenthesis.
+        completion_tupleVariantTabStops_enable: bool = "true",
+        /// Whether to complete enum variants that need an import twice, once adding the import and
+        /// once inserting the full path of the variant instead.
+        completion_variantImportAlternatives_enable: bool = "false",
+        /// Whether to use the name of the struct wrapped by a single-field tuple variant as the
+        /// placeholder of its field when completing it, as in `Wrap(MyStruct)`, instead of `()`.
+        completion_tupleVariantTypePlaceholder_enable: bool = "false",
+        /// Whether to show the name of the defining crate in the detail of enum variant
+        /// completions from other crates, when a variant of the same name from another crate is
+        /// completed as well.
+        completion_variantCrateNames_enable: bool = "false",
+        /// Maximum number of characters of the first line of an enum variant's documentation to show
+        /// in the detail of its completion. If `None`, the documentation is not shown.
+        completion_variantDocSummary_limit: Option<usize> = "null",
+        /// Whether to collapse the documentation of enum variant completions into a single
+        /// paragraph, leaving out its code blocks, to keep the documentation popup small. The
+        /// generated signatures are kept.
+        completion_variantDocs_compact: bool = "false",
+        /// Whether to show the types that type aliases stand for in the detail of struct and enum
+        /// variant completions, instead of the aliases the fields are written with.
+        completion_variantFieldAliases_expand: bool = "false",
+        /// Whether to add an import when completing an enum variant that is not in scope. If disabled,
+        /// the variant is inserted with the path the import would have brought into scope instead, for
+        /// clients that handle imports themselves.
+        completion_variantImportEdits_enable: bool = "true",
+        /// Whether to fill the `Option` fields of record enum variant snippets with `None`, so that only
+        /// the other fields get a tab stop.
+        completion_variantOptionFieldsNone_enable: bool = "false",
+        /// Whether to add parenthesis or braces and field snippets when completing tuple and record
+        /// enum variants. Defaults to whether `#rust-analyzer.completion.callable.snippets#` adds
+        /// them for functions.
+        completion_variantParens_enable: Option<bool> = "null",
+        /// Whether to prefix the paths that enum variants are qualified with when completed, like
+        /// `crate::` or `self::`, the same way as imports according to `#rust-analyzer.imports.prefix#`.
+        completion_variantPaths_importPrefix: bool = "false",
+        /// Whether to show the size of enum variants, including the tag, in the detail of their completions,
+        /// as in `(size: 8 bytes)`. Nothing is shown when the size can't be computed, e.g. for variants
+        /// of generic enums.
+        completion_variantPayloadSize_enable: bool = "false",
+        /// Whether to skip enum variant completions that don't fuzzily match the identifier typed so
+        /// far before rendering them, instead of leaving all the filtering to the client. This can speed
+        /// up completions from large enums.
+        completion_variantPrefilter_enable: bool = "false",
+        /// Whether to show the names of the fields of record enum variants in the detail of their
+        /// completions, as in `E::A { a: u8, b: String }`, instead of only their types, as in
+        /// `E::A { u8, String }`.
+        completion_variantRecordFieldNames_enable: bool = "true",
+        /// Whether to show the integer type and discriminant of enum variants in the detail
+        /// of their completions when the enum has a `#[repr]` integer type, as in `A: u8 = 0`.
+        completion_variantReprDetail_enable: bool = "false",
+        /// Whether to fill the fields of tuple and record enum variants with `todo!()` when
+        /// completing them as the return value of a trait method implementation, so that the code
+        /// compiles right away.
+        completion_variantTodoFields_enable: bool = "false",
 
         /// List of rust-analyzer diagnostics to disable.
         diagnostics_disabled: FxHashSet<String> = "[]",
@@ -1343,17 +1413,18 @@ fn insert_use_config(&self) -> InsertUseConfig {
     }
 
     pub fn completion(&self) -> CompletionConfig {
+        let callable = match self.data.completion_callable_snippets {
+            CallableCompletionDef::FillArguments => Some(CallableSnippets::FillArguments),
+            CallableCompletionDef::AddParentheses => Some(CallableSnippets::AddParentheses),
+            CallableCompletionDef::None => None,
+        };
         CompletionConfig {
             enable_postfix_completions: self.data.completion_postfix_enable,
             enable_imports_on_the_fly: self.data.completion_autoimport_enable
                 && completion_item_edit_resolve(&self.caps),
             enable_self_on_the_fly: self.data.completion_autoself_enable,
             enable_private_editable: self.data.completion_privateEditable_enable,
-            callable: match self.data.completion_callable_snippets {
-                CallableCompletionDef::FillArguments => Some(CallableSnippets::FillArguments),
-                CallableCompletionDef::AddParentheses => Some(CallableSnippets::AddParentheses),
-                CallableCompletionDef::None => None,
-            },
+            callable,
             insert_use: self.insert_use_config(),
             prefer_no_std: self.data.imports_prefer_no_std,
             snippet_cap: SnippetCap::new(try_or_def!(
@@ -1368,6 +1439,34 @@ pub fn completion(&self) -> CompletionConfig {
             )),
             snippets: self.snippets.clone(),
             limit: self.data.completion_limit,
+            tuple_fields_limit: self.data.completion_tupleFields_limit,
+            full_variant_paths: self.data.completion_fullVariantPaths_enable,
+            variant_doc_summary_limit: self.data.completion_variantDocSummary_limit,
+            group_expected_variants: self.data.completion_groupExpectedVariants_enable,
+            tuple_variant_fn_detail: self.data.completion_tupleVariantFnDetail_enable,
+            add_call_parenthesis_for_variants: self
+                .data
+                .completion_variantParens_enable
+                .unwrap_or(callable.is_some()),
+            variant_crate_names: self.data.completion_variantCrateNames_enable,
+            record_variant_brace_spaces: self.data.completion_recordVariantBraceSpaces_enable,
+            compact_variant_docs: self.data.completion_variantDocs_compact,
+            todo_variant_fields: self.data.completion_variantTodoFields_enable,
+            newtype_variant_type_placeholder: self
+                .data
+                .completion_tupleVariantTypePlaceholder_enable,
+            no_import_edits: !self.data.completion_variantImportEdits_enable,
+            variant_repr_detail: self.data.completion_variantReprDetail_enable,
+            variant_paths_import_prefix: self.data.completion_variantPaths_importPrefix,
+            single_cursor_tuple_variants: !self.data.completion_tupleVariantTabStops_enable,
+            variant_import_alternatives: self.data.completion_variantImportAlternatives_enable,
+            expand_variant_field_aliases: self.data.completion_variantFieldAliases_expand,
+            variant_prefilter: self.data.completion_variantPrefilter_enable,
+            option_fields_none: self.data.completion_variantOptionFieldsNone_enable,
+            variant_payload_size: self.data.completion_variantPayloadSize_enable,
+            variant_record_field_names: self.data.completion_variantRecordFieldNames_enable,
+            resolve_variant_docs: completion_item_docs_resolve(&self.caps),
+            variant_item_hook: None,
         }
     }
 
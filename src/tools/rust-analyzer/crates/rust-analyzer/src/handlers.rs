@@ -874,6 +874,34 @@ pub(crate) fn handle_completion_resolve(
     let line_index = snap.file_line_index(file_id)?;
     let offset = from_proto::offset(&line_index, resolve_data.position.position)?;
 
+    if let Some(path) = resolve_data.variant.as_ref().and_then(|variant| variant.path.as_deref()) {
+        let docs = snap.analysis.variant_documentation(
+            &snap.config.completion(),
+            FilePosition { file_id, offset },
+            path,
+        )?;
+        if let Some(docs) = docs {
+            // The variant's own docs go below the generated signatures the item was sent with
+            let docs = crate::markdown::format_docs(docs.as_str());
+            let value = match original_completion.documentation.take() {
+                Some(lsp_types::Documentation::MarkupContent(it)) => {
+                    format!("{}\n\n{docs}", it.value)
+                }
+                Some(lsp_types::Documentation::String(it)) => format!("{it}\n\n{docs}"),
+                None => docs,
+            };
+            original_completion.documentation =
+                Some(lsp_types::Documentation::MarkupContent(lsp_types::MarkupContent {
+                    kind: lsp_types::MarkupKind::Markdown,
+                    value,
+                }));
+        }
+    }
+
+    if resolve_data.imports.is_empty() {
+        return Ok(original_completion);
+    }
+
     let additional_edits = snap
         .analysis
         .resolve_completion_edits(
@@ -589,6 +589,8 @@ impl Request for OnTypeFormatting {
 pub struct CompletionResolveData {
     pub position: lsp_types::TextDocumentPositionParams,
     pub imports: Vec<CompletionImport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<CompletionVariant>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -600,6 +602,24 @@ pub struct CompletionImport {
     pub imported_name: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionVariant {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    pub index: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<CompletionVariantField>>,
+    #[serde(default)]
+    pub is_newtype: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CompletionVariantField {
+    pub name: String,
+    pub ty: String,
+}
+
 #[derive(Debug, Deserialize, Default)]
 pub struct ClientCommandOptions {
     pub commands: Vec<String>,
@@ -10,8 +10,8 @@
     CompletionItemKind, CompletionRelevance, Documentation, FileId, FileRange, FileSystemEdit,
     Fold, FoldKind, Highlight, HlMod, HlOperator, HlPunct, HlRange, HlTag, Indel, InlayHint,
     InlayHintLabel, InlayHintLabelPart, InlayKind, Markup, NavigationTarget, ReferenceCategory,
-    RenameError, Runnable, Severity, SignatureHelp, SourceChange, StructureNodeKind, SymbolKind,
-    TextEdit, TextRange, TextSize,
+    RenameError, Runnable, Severity, SignatureHelp, SortBucket, SourceChange, StructureNodeKind,
+    SymbolKind, TextEdit, TextRange, TextSize,
 };
 use itertools::Itertools;
 use serde_json::to_value;
@@ -237,6 +237,7 @@ fn completion_item(
 ) {
     let insert_replace_support = config.insert_replace_support().then_some(tdpp.position);
     let ref_match = item.ref_match();
+    let sort_bucket = config.completion().group_expected_variants.then_some(item.sort_bucket);
     let lookup = item.lookup().to_string();
 
     let mut additional_text_edits = Vec::new();
@@ -299,27 +300,37 @@ fn completion_item(
         });
     }
 
-    set_score(&mut lsp_item, max_relevance, item.relevance);
+    set_score(&mut lsp_item, max_relevance, sort_bucket, item.relevance);
 
+    let mut imports = Vec::new();
     if config.completion().enable_imports_on_the_fly {
-        if !item.import_to_add.is_empty() {
-            let imports: Vec<_> = item
-                .import_to_add
-                .into_iter()
-                .filter_map(|import_edit| {
-                    let import_path = &import_edit.import_path;
-                    let import_name = import_path.segments().last()?;
-                    Some(lsp_ext::CompletionImport {
-                        full_import_path: import_path.to_string(),
-                        imported_name: import_name.to_string(),
-                    })
+        imports = item
+            .import_to_add
+            .into_iter()
+            .filter_map(|import_edit| {
+                let import_path = &import_edit.import_path;
+                let import_name = import_path.segments().last()?;
+                Some(lsp_ext::CompletionImport {
+                    full_import_path: import_path.to_string(),
+                    imported_name: import_name.to_string(),
                 })
-                .collect();
-            if !imports.is_empty() {
-                let data = lsp_ext::CompletionResolveData { position: tdpp.clone(), imports };
-                lsp_item.data = Some(to_value(data).unwrap());
-            }
-        }
+            })
+            .collect();
+    }
+    let variant = item.variant_index.map(|index| lsp_ext::CompletionVariant {
+        path: item.variant_path,
+        index,
+        fields: item.variant_fields.map(|fields| {
+            fields
+                .into_iter()
+                .map(|(name, ty)| lsp_ext::CompletionVariantField { name: name.into(), ty })
+                .collect()
+        }),
+        is_newtype: item.is_newtype_variant,
+    });
+    if !imports.is_empty() || variant.is_some() {
+        let data = lsp_ext::CompletionResolveData { position: tdpp.clone(), imports, variant };
+        lsp_item.data = Some(to_value(data).unwrap());
     }
 
     if let Some((label, indel, relevance)) = ref_match {
@@ -328,7 +339,7 @@ fn completion_item(
             .additional_text_edits
             .get_or_insert_with(Default::default)
             .push(self::text_edit(line_index, indel));
-        set_score(&mut lsp_item_with_ref, max_relevance, relevance);
+        set_score(&mut lsp_item_with_ref, max_relevance, sort_bucket, relevance);
         acc.push(lsp_item_with_ref);
     };
 
@@ -337,6 +348,7 @@ fn completion_item(
     fn set_score(
         res: &mut lsp_types::CompletionItem,
         max_relevance: u32,
+        sort_bucket: Option<SortBucket>,
         relevance: CompletionRelevance,
     ) {
         if relevance.is_relevant() && relevance.score() == max_relevance {
@@ -349,7 +361,12 @@ fn set_score(
         // by the client. Hex format is used because it is easier to
         // visually compare very large values, which the sort text
         // tends to be since it is the opposite of the score.
-        res.sort_text = Some(format!("{sort_score:08x}"));
+        let sort_text = format!("{sort_score:08x}");
+        // Prefixing with the bucket sorts all items of a bucket before those of the next one
+        res.sort_text = Some(match sort_bucket {
+            Some(bucket) => format!("{}{sort_text}", bucket as u8),
+            None => sort_text,
+        });
     }
 }
 